@@ -0,0 +1,84 @@
+use criterion::{black_box, criterion_group, criterion_main, BenchmarkId, Criterion};
+use num_bigint::{BigInt, RandBigInt};
+use num_traits::One;
+use tiny_ec::curve::Curve;
+use tiny_ec::curve_registry::{get_curve, list_curves};
+use tiny_ec::fixed_base::{generator_table, mul_generator};
+use tiny_ec::point::{Point, DEFAULT_WNAF_WIDTH};
+use tiny_ec::scalar::Scalar;
+
+// Variable-base multiplication of the generator by a random scalar below n,
+// on every registry curve
+fn scalar_multiplication(c: &mut Criterion) {
+    let mut group = c.benchmark_group("scalar_mul");
+    group.sample_size(10);
+    for name in list_curves() {
+        let curve = get_curve(&name).unwrap();
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+        let k = rand::thread_rng().gen_bigint_range(&BigInt::one(), &curve.field.n);
+
+        group.bench_with_input(BenchmarkId::new("double_and_add", &name), &k, |b, k| {
+            b.iter(|| Point::mul_double_and_add(black_box(&g), k.clone()))
+        });
+        for w in [4, DEFAULT_WNAF_WIDTH, 6] {
+            let id = BenchmarkId::new(format!("wnaf_w{}", w), &name);
+            group.bench_with_input(id, &k, |b, k| {
+                b.iter(|| Point::mul_wnaf(black_box(&g), k.clone(), w))
+            });
+        }
+    }
+    group.finish();
+}
+
+// Generator multiplication by a secret scalar: the ladder against the cached table
+fn generator_multiplication(c: &mut Criterion) {
+    let mut group = c.benchmark_group("generator_mul");
+    group.sample_size(10);
+    for name in list_curves() {
+        let curve = get_curve(&name).unwrap();
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+        let k = Scalar::random(&curve);
+        generator_table(&curve).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("ladder", &name), &k, |b, k| {
+            b.iter(|| Point::mul_montgomery(black_box(&g), k.value().clone()))
+        });
+        group.bench_with_input(BenchmarkId::new("table", &name), &k, |b, k| {
+            b.iter(|| mul_generator(black_box(&curve), k))
+        });
+    }
+    group.finish();
+}
+
+// u1·G + u2·Q as in ECDSA verification on the Koblitz curves, with and without
+// the GLV endomorphism
+fn double_scalar_multiplication(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul2");
+    group.sample_size(10);
+    for name in ["secp160k1", "secp192k1", "secp224k1", "secp256k1"] {
+        let glv = get_curve(name).unwrap();
+        let plain = Curve::new(
+            glv.a.clone(),
+            glv.b.clone(),
+            glv.field.clone(),
+            glv.name.clone(),
+        );
+        for (label, curve) in [("glv", &glv), ("jsf", &plain)] {
+            let g = Point::new(curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+            let q = Point::mul_scalar(&g, &Scalar::random(curve)).unwrap();
+            let (u1, u2) = (Scalar::random(curve), Scalar::random(curve));
+            group.bench_function(BenchmarkId::new(label, name), |b| {
+                b.iter(|| Point::mul2(black_box(&g), &u1, &q, &u2))
+            });
+        }
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    scalar_multiplication,
+    generator_multiplication,
+    double_scalar_multiplication
+);
+criterion_main!(benches);
@@ -1,7 +1,14 @@
+use crate::error::Error;
+use crate::field_element::{FieldBackend, FieldElement, PrimeField};
+use crate::point::Point;
+use crate::utils::is_probable_prime;
 use num_bigint::BigInt;
-use num_traits::Zero;
+use num_traits::{Euclid, One, Zero};
 use std::fmt;
 
+// SEC 1 (section 3.1.1.2.1) requires p^B != 1 (mod n) for all 1 <= B < 100
+pub const MOV_THRESHOLD: u32 = 100;
+
 #[derive(Clone)]
 // Simple Weierstrass curve structure
 // y^2 = x^3 + a*x + b
@@ -10,31 +17,230 @@ pub struct Curve {
     pub b: BigInt,
     pub field: SubGroup,
     pub name: String,
+    base_field: PrimeField,
+    endomorphism: Option<Endomorphism>,
 }
 
 impl Curve {
     pub fn new(a: BigInt, b: BigInt, field: SubGroup, name: String) -> Self {
-        Curve { a, b, field, name }
+        Curve {
+            base_field: PrimeField::new_unchecked(field.p.clone()),
+            endomorphism: None,
+            a,
+            b,
+            field,
+            name,
+        }
+    }
+
+    // Like new, but refuses parameters that fail any check of validate
+    pub fn new_checked(a: BigInt, b: BigInt, field: SubGroup, name: String) -> Result<Self, Error> {
+        let curve = Curve::new(a, b, field, name);
+        let report = curve.validate();
+        if !report.is_valid() {
+            return Err(Error::InvalidCurve(report));
+        }
+        Ok(curve)
+    }
+
+    // The same curve with its base field arithmetic done by the given backend.
+    // All backends agree; they differ only in speed.
+    pub fn with_field_backend(mut self, backend: FieldBackend) -> Result<Self, Error> {
+        self.base_field = PrimeField::with_backend(self.field.p.clone(), backend)?;
+        Ok(self)
+    }
+
+    // The same curve with an endomorphism for GLV scalar multiplication, refused
+    // unless β and λ are non-trivial cube roots of unity with λ·G = (β·Gx, Gy) and
+    // both basis vectors lie in the kernel of the decomposition
+    pub fn with_endomorphism(self, endomorphism: Endomorphism) -> Result<Self, Error> {
+        let (p, n) = (&self.field.p, &self.field.n);
+        let one = BigInt::one();
+        let three = BigInt::from(3);
+        let cube_root = |x: &BigInt, m: &BigInt| {
+            let x = x.rem_euclid(m);
+            x != one && x.modpow(&three, m) == one
+        };
+        let kernel = endomorphism
+            .basis
+            .iter()
+            .all(|(a, b)| (a + b * &endomorphism.lambda).rem_euclid(n).is_zero());
+        if !self.a.is_zero()
+            || !cube_root(&endomorphism.beta, p)
+            || !cube_root(&endomorphism.lambda, n)
+            || !kernel
+        {
+            return Err(Error::InvalidEndomorphism);
+        }
+        let g = Point::new(&self, self.field.g.0.clone(), self.field.g.1.clone())?;
+        let image = Point::new(&self, &endomorphism.beta * &g.x, g.y.clone())?;
+        if Point::mul_double_and_add(&g, endomorphism.lambda.clone())? != image {
+            return Err(Error::InvalidEndomorphism);
+        }
+        Ok(self.with_endomorphism_unchecked(endomorphism))
+    }
+
+    // For the built-in curves, whose endomorphisms are checked by the tests
+    pub(crate) fn with_endomorphism_unchecked(mut self, endomorphism: Endomorphism) -> Self {
+        self.endomorphism = Some(endomorphism);
+        self
+    }
+
+    pub fn endomorphism(&self) -> Option<&Endomorphism> {
+        self.endomorphism.as_ref()
     }
 
-    fn mod_pow(base: &BigInt, exponent: usize, modulus: &BigInt) -> BigInt {
-        base.modpow(&(BigInt::from(exponent)), &modulus)
+    // F_p, in which all point arithmetic on this curve is done
+    pub fn base_field(&self) -> &PrimeField {
+        &self.base_field
     }
 
     pub fn is_singular(&self) -> bool {
-        (4 * Self::mod_pow(&self.a, 3, &self.field.p)
-            + 27 * Self::mod_pow(&self.b, 2, &self.field.p))
-            % &self.field.p
-            == BigInt::zero()
+        let fp = &self.base_field;
+        let a = fp.element(&self.a);
+        let b = fp.element(&self.b);
+        (&(&a.square() * &a) * 4 + &b.square() * 27).is_zero()
     }
 
+    // Curves with p < 2 have no points besides the point at infinity
     pub fn on_curve(&self, x: &BigInt, y: &BigInt) -> bool {
-        (Self::mod_pow(&y, 2, &self.field.p)
-            - Self::mod_pow(&x, 3, &self.field.p)
-            - &self.a * x
-            - &self.b)
-            % &self.field.p
-            == BigInt::zero()
+        if self.field.p < BigInt::from(2) {
+            return false;
+        }
+        let fp = &self.base_field;
+        let x = fp.element(x);
+        self.y_squared(&x) == fp.element(y).square()
+    }
+
+    // x^3 + ax + b, the right-hand side of the curve equation
+    pub fn y_squared<'c>(&'c self, x: &FieldElement<'c>) -> FieldElement<'c> {
+        let fp = &self.base_field;
+        &(&x.square() + &fp.element(&self.a)) * x + fp.element(&self.b)
+    }
+
+    // Elliptic curve domain parameter validation (SEC 1, section 3.1.1.2.1)
+    pub fn validate(&self) -> ValidationReport {
+        self.validate_with_mov_threshold(MOV_THRESHOLD)
+    }
+
+    pub fn validate_with_mov_threshold(&self, mov_threshold: u32) -> ValidationReport {
+        let SubGroup { p, g, n, h } = &self.field;
+        let in_field = |x: &BigInt| x >= &BigInt::zero() && x < p;
+        // With p or n below 2 there is no field or subgroup to do arithmetic in
+        let p_usable = p > &BigInt::one();
+        let n_usable = n > &BigInt::one();
+
+        let p_is_prime = is_probable_prime(p);
+        let n_is_prime = is_probable_prime(n);
+        let generator_on_curve =
+            p_usable && in_field(&g.0) && in_field(&g.1) && self.on_curve(&g.0, &g.1);
+
+        let generator_has_order_n = generator_on_curve
+            && n_usable
+            && Point::new(self, g.0.clone(), g.1.clone())
+                .and_then(|g| Point::mul_double_and_add(&g, n.clone()))
+                .is_ok_and(|ng| ng.is_infinity());
+
+        // Hasse: |#E - (p + 1)| <= 2·sqrt(p) with #E = h·n
+        let order = h * n;
+        let trace: BigInt = &order - p - 1;
+        let cofactor_in_hasse_bound = h >= &BigInt::one() && &trace * &trace <= p * 4;
+
+        let mut embedding_degree_above_threshold = p_usable && n_usable;
+        if embedding_degree_above_threshold {
+            let mut power = BigInt::one();
+            for _ in 1..mov_threshold {
+                power = (power * p) % n;
+                if power.is_one() {
+                    embedding_degree_above_threshold = false;
+                    break;
+                }
+            }
+        }
+
+        ValidationReport {
+            p_is_prime,
+            coefficients_reduced: in_field(&self.a) && in_field(&self.b),
+            non_singular: p_usable && !self.is_singular(),
+            generator_on_curve,
+            n_is_prime,
+            generator_has_order_n,
+            cofactor_in_hasse_bound,
+            not_anomalous: &order != p,
+            embedding_degree_above_threshold,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+// Outcome of each domain parameter check performed by Curve::validate
+pub struct ValidationReport {
+    pub p_is_prime: bool,
+    pub coefficients_reduced: bool,
+    pub non_singular: bool,
+    pub generator_on_curve: bool,
+    pub n_is_prime: bool,
+    pub generator_has_order_n: bool,
+    pub cofactor_in_hasse_bound: bool,
+    pub not_anomalous: bool,
+    pub embedding_degree_above_threshold: bool,
+}
+
+impl ValidationReport {
+    fn checks(&self) -> [(bool, &'static str); 9] {
+        [
+            (self.p_is_prime, "p is not prime"),
+            (self.coefficients_reduced, "a and b are not reduced mod p"),
+            (self.non_singular, "discriminant is zero"),
+            (self.generator_on_curve, "G is not on the curve"),
+            (self.n_is_prime, "n is not prime"),
+            (
+                self.generator_has_order_n,
+                "n·G is not the point at infinity",
+            ),
+            (
+                self.cofactor_in_hasse_bound,
+                "h·n is outside the Hasse bound",
+            ),
+            (self.not_anomalous, "curve is anomalous"),
+            (
+                self.embedding_degree_above_threshold,
+                "MOV embedding degree is too small",
+            ),
+        ]
+    }
+
+    // Mathematical consistency of the parameters, without the security checks
+    pub fn is_well_formed(&self) -> bool {
+        self.checks()[..7].iter().all(|(passed, _)| *passed)
+    }
+
+    // Resistance to the anomalous curve and MOV attacks
+    pub fn is_secure(&self) -> bool {
+        self.not_anomalous && self.embedding_degree_above_threshold
+    }
+
+    pub fn is_valid(&self) -> bool {
+        self.is_well_formed() && self.is_secure()
+    }
+
+    // Descriptions of the failed checks
+    pub fn failures(&self) -> Vec<&'static str> {
+        self.checks()
+            .iter()
+            .filter(|(passed, _)| !passed)
+            .map(|(_, failure)| *failure)
+            .collect()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.is_valid() {
+            write!(f, "all checks passed")
+        } else {
+            write!(f, "{}", self.failures().join(", "))
+        }
     }
 }
 
@@ -56,6 +262,32 @@ impl PartialEq for Curve {
 
 impl Eq for Curve {}
 
+#[derive(Clone, Debug, PartialEq, Eq)]
+// Endomorphism φ(x, y) = (β·x, y) of an a = 0 curve, acting on the subgroup as
+// multiplication by λ. The basis vectors (a, b) satisfy a + b·λ ≡ 0 (mod n) and are
+// short, about sqrt(n), so a scalar splits into two halves of half the length.
+pub struct Endomorphism {
+    pub beta: BigInt,
+    pub lambda: BigInt,
+    pub basis: [(BigInt, BigInt); 2],
+}
+
+impl Endomorphism {
+    // (k1, k2) with k ≡ k1 + k2·λ (mod n), both about half the bit length of n
+    // (Guide to Elliptic Curve Cryptography, algorithm 3.74)
+    pub fn decompose(&self, k: &BigInt, n: &BigInt) -> (BigInt, BigInt) {
+        let [(a1, b1), (a2, b2)] = &self.basis;
+        // round(x / n) for n > 0
+        let two_n: BigInt = n * 2;
+        let round = |x: BigInt| -> BigInt { (x * BigInt::from(2) + n).div_euclid(&two_n) };
+        let c1 = round(b2 * k);
+        let c2 = round(-b1 * k);
+        let k1 = k - &c1 * a1 - &c2 * a2;
+        let k2 = -(&c1 * b1) - &c2 * b2;
+        (k1, k2)
+    }
+}
+
 #[derive(Clone)]
 pub struct SubGroup {
     pub p: BigInt,           // Prime field of the subgroup curve points
@@ -1,6 +1,12 @@
-use crate::curve::{Curve, SubGroup};
+use crate::curve::{Curve, Endomorphism, SubGroup};
+use crate::error::Error;
+use crate::point::Point;
 use num_bigint::BigInt;
-use num_traits::Num;
+use num_traits::{Euclid, Num};
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+use std::sync::{RwLock, RwLockReadGuard};
 
 pub struct CurveParams {
     pub p: BigInt,
@@ -11,70 +17,869 @@ pub struct CurveParams {
     pub h: BigInt,
 }
 
+// Built-in curve with its domain parameters as hex strings
+struct CurveSpec {
+    name: &'static str,
+    aliases: &'static [&'static str],
+    oid: &'static str,
+    p: &'static str,
+    a: &'static str,
+    b: &'static str,
+    gx: &'static str,
+    gy: &'static str,
+    n: &'static str,
+    h: &'static str,
+}
+
+impl CurveSpec {
+    fn matches(&self, name: &str) -> bool {
+        self.name.eq_ignore_ascii_case(name)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(name))
+    }
+
+    fn params(&self) -> CurveParams {
+        CurveParams {
+            p: hex(self.p),
+            a: hex(self.a),
+            b: hex(self.b),
+            g: (hex(self.gx), hex(self.gy)),
+            n: hex(self.n),
+            h: hex(self.h),
+        }
+    }
+
+    fn to_curve(&self) -> Curve {
+        let curve_params = self.params();
+        let sub_group = SubGroup {
+            p: curve_params.p,
+            g: curve_params.g,
+            n: curve_params.n,
+            h: curve_params.h,
+        };
+
+        let curve = Curve::new(
+            curve_params.a,
+            curve_params.b,
+            sub_group,
+            String::from(self.name),
+        );
+        match ENDOMORPHISMS.iter().find(|spec| spec.curve == self.name) {
+            Some(spec) => curve.with_endomorphism_unchecked(spec.to_endomorphism()),
+            None => curve,
+        }
+    }
+}
+
+fn hex(s: &str) -> BigInt {
+    BigInt::from_str_radix(s, 16).expect("Registry parameters are valid hex")
+}
+
 // https://github.com/alexmgr/tinyec/blob/master/tinyec/registry.py
-pub fn get_curve(name: &str) -> Result<Curve, &'static str> {
-    let curve_params = match name.to_lowercase().as_str() {
-        "brainpoolp160r1" => CurveParams {
-            p: BigInt::from_str_radix("e95e4a5f737059dc60dfc7ad95b3d8139515620f", 16).unwrap(),
-            a: BigInt::from_str_radix("340e7be2a280eb74e2be61bada745d97e8f7c300", 16).unwrap(),
-            b: BigInt::from_str_radix("1e589a8595423412134faa2dbdec95c8d8675e58", 16).unwrap(),
-            g: (
-                BigInt::from_str_radix("bed5af16ea3f6a4f62938c4631eb5af7bdbcdbc3", 16).unwrap(),
-                BigInt::from_str_radix("1667cb477a1a8ec338f94741669c976316da6321", 16).unwrap(),
+// https://www.secg.org/sec2-v2.pdf
+// https://www.rfc-editor.org/rfc/rfc5639
+const CURVES: &[CurveSpec] = &[
+    CurveSpec {
+        name: "brainpoolP160r1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.1",
+        p: "e95e4a5f737059dc60dfc7ad95b3d8139515620f",
+        a: "340e7be2a280eb74e2be61bada745d97e8f7c300",
+        b: "1e589a8595423412134faa2dbdec95c8d8675e58",
+        gx: "bed5af16ea3f6a4f62938c4631eb5af7bdbcdbc3",
+        gy: "1667cb477a1a8ec338f94741669c976316da6321",
+        n: "e95e4a5f737059dc60df5991d45029409e60fc09",
+        h: "1",
+    },
+    CurveSpec {
+        name: "brainpoolP160t1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.2",
+        p: "e95e4a5f737059dc60dfc7ad95b3d8139515620f",
+        a: "e95e4a5f737059dc60dfc7ad95b3d8139515620c",
+        b: "7a556b6dae535b7b51ed2c4d7daa7a0b5c55f380",
+        gx: "b199b13b9b34efc1397e64baeb05acc265ff2378",
+        gy: "add6718b7c7c1961f0991b842443772152c9e0ad",
+        n: "e95e4a5f737059dc60df5991d45029409e60fc09",
+        h: "1",
+    },
+    CurveSpec {
+        name: "brainpoolP192r1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.3",
+        p: "c302f41d932a36cda7a3463093d18db78fce476de1a86297",
+        a: "6a91174076b1e0e19c39c031fe8685c1cae040e5c69a28ef",
+        b: "469a28ef7c28cca3dc721d044f4496bcca7ef4146fbf25c9",
+        gx: "c0a0647eaab6a48753b033c56cb0f0900a2f5c4853375fd6",
+        gy: "14b690866abd5bb88b5f4828c1490002e6773fa2fa299b8f",
+        n: "c302f41d932a36cda7a3462f9e9e916b5be8f1029ac4acc1",
+        h: "1",
+    },
+    CurveSpec {
+        name: "brainpoolP192t1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.4",
+        p: "c302f41d932a36cda7a3463093d18db78fce476de1a86297",
+        a: "c302f41d932a36cda7a3463093d18db78fce476de1a86294",
+        b: "13d56ffaec78681e68f9deb43b35bec2fb68542e27897b79",
+        gx: "3ae9e58c82f63c30282e1fe7bbf43fa72c446af6f4618129",
+        gy: "097e2c5667c2223a902ab5ca449d0084b7e5b3de7ccc01c9",
+        n: "c302f41d932a36cda7a3462f9e9e916b5be8f1029ac4acc1",
+        h: "1",
+    },
+    CurveSpec {
+        name: "brainpoolP224r1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.5",
+        p: "d7c134aa264366862a18302575d1d787b09f075797da89f57ec8c0ff",
+        a: "68a5e62ca9ce6c1c299803a6c1530b514e182ad8b0042a59cad29f43",
+        b: "2580f63ccfe44138870713b1a92369e33e2135d266dbb372386c400b",
+        gx: "0d9029ad2c7e5cf4340823b2a87dc68c9e4ce3174c1e6efdee12c07d",
+        gy: "58aa56f772c0726f24c6b89e4ecdac24354b9e99caa3f6d3761402cd",
+        n: "d7c134aa264366862a18302575d0fb98d116bc4b6ddebca3a5a7939f",
+        h: "1",
+    },
+    CurveSpec {
+        name: "brainpoolP224t1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.6",
+        p: "d7c134aa264366862a18302575d1d787b09f075797da89f57ec8c0ff",
+        a: "d7c134aa264366862a18302575d1d787b09f075797da89f57ec8c0fc",
+        b: "4b337d934104cd7bef271bf60ced1ed20da14c08b3bb64f18a60888d",
+        gx: "6ab1e344ce25ff3896424e7ffe14762ecb49f8928ac0c76029b4d580",
+        gy: "0374e9f5143e568cd23f3f4d7c0d4b1e41c8cc0d1c6abd5f1a46db4c",
+        n: "d7c134aa264366862a18302575d0fb98d116bc4b6ddebca3a5a7939f",
+        h: "1",
+    },
+    CurveSpec {
+        name: "brainpoolP256r1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.7",
+        p: "a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377",
+        a: "7d5a0975fc2c3057eef67530417affe7fb8055c126dc5c6ce94a4b44f330b5d9",
+        b: "26dc5c6ce94a4b44f330b5d9bbd77cbf958416295cf7e1ce6bccdc18ff8c07b6",
+        gx: "8bd2aeb9cb7e57cb2c4b482ffc81b7afb9de27e1e3bd23c23a4453bd9ace3262",
+        gy: "547ef835c3dac4fd97f8461a14611dc9c27745132ded8e545c1d54c72f046997",
+        n: "a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a7",
+        h: "1",
+    },
+    CurveSpec {
+        name: "brainpoolP256t1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.8",
+        p: "a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377",
+        a: "a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5374",
+        b: "662c61c430d84ea4fe66a7733d0b76b7bf93ebc4af2f49256ae58101fee92b04",
+        gx: "a3e8eb3cc1cfe7b7732213b23a656149afa142c47aafbc2b79a191562e1305f4",
+        gy: "2d996c823439c56d7f7b22e14644417e69bcb6de39d027001dabe8f35b25c9be",
+        n: "a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a7",
+        h: "1",
+    },
+    CurveSpec {
+        name: "brainpoolP320r1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.9",
+        p: concat!(
+            "d35e472036bc4fb7e13c785ed201e065f98fcfa6",
+            "f6f40def4f92b9ec7893ec28fcd412b1f1b32e27"
+        ),
+        a: concat!(
+            "3ee30b568fbab0f883ccebd46d3f3bb8a2a73513",
+            "f5eb79da66190eb085ffa9f492f375a97d860eb4"
+        ),
+        b: concat!(
+            "520883949dfdbc42d3ad198640688a6fe13f4134",
+            "9554b49acc31dccd884539816f5eb4ac8fb1f1a6"
+        ),
+        gx: concat!(
+            "43bd7e9afb53d8b85289bcc48ee5bfe6f20137d1",
+            "0a087eb6e7871e2a10a599c710af8d0d39e20611"
+        ),
+        gy: concat!(
+            "14fdd05545ec1cc8ab4093247f77275e0743ffed",
+            "117182eaa9c77877aaac6ac7d35245d1692e8ee1"
+        ),
+        n: concat!(
+            "d35e472036bc4fb7e13c785ed201e065f98fcfa5",
+            "b68f12a32d482ec7ee8658e98691555b44c59311"
+        ),
+        h: "1",
+    },
+    CurveSpec {
+        name: "brainpoolP320t1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.10",
+        p: concat!(
+            "d35e472036bc4fb7e13c785ed201e065f98fcfa6",
+            "f6f40def4f92b9ec7893ec28fcd412b1f1b32e27"
+        ),
+        a: concat!(
+            "d35e472036bc4fb7e13c785ed201e065f98fcfa6",
+            "f6f40def4f92b9ec7893ec28fcd412b1f1b32e24"
+        ),
+        b: concat!(
+            "a7f561e038eb1ed560b3d147db782013064c19f2",
+            "7ed27c6780aaf77fb8a547ceb5b4fef422340353"
+        ),
+        gx: concat!(
+            "925be9fb01afc6fb4d3e7d4990010f813408ab10",
+            "6c4f09cb7ee07868cc136fff3357f624a21bed52"
+        ),
+        gy: concat!(
+            "63ba3a7a27483ebf6671dbef7abb30ebee084e58",
+            "a0b077ad42a5a0989d1ee71b1b9bc0455fb0d2c3"
+        ),
+        n: concat!(
+            "d35e472036bc4fb7e13c785ed201e065f98fcfa5",
+            "b68f12a32d482ec7ee8658e98691555b44c59311"
+        ),
+        h: "1",
+    },
+    CurveSpec {
+        name: "brainpoolP384r1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.11",
+        p: concat!(
+            "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b4",
+            "12b1da197fb71123acd3a729901d1a71874700133107ec53"
+        ),
+        a: concat!(
+            "7bc382c63d8c150c3c72080ace05afa0c2bea28e4fb22787",
+            "139165efba91f90f8aa5814a503ad4eb04a8c7dd22ce2826"
+        ),
+        b: concat!(
+            "04a8c7dd22ce28268b39b55416f0447c2fb77de107dcd2a6",
+            "2e880ea53eeb62d57cb4390295dbc9943ab78696fa504c11"
+        ),
+        gx: concat!(
+            "1d1c64f068cf45ffa2a63a81b7c13f6b8847a3e77ef14fe3",
+            "db7fcafe0cbd10e8e826e03436d646aaef87b2e247d4af1e"
+        ),
+        gy: concat!(
+            "8abe1d7520f9c2a45cb1eb8e95cfd55262b70b29feec5864",
+            "e19c054ff99129280e4646217791811142820341263c5315"
+        ),
+        n: concat!(
+            "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b3",
+            "1f166e6cac0425a7cf3ab6af6b7fc3103b883202e9046565"
+        ),
+        h: "1",
+    },
+    CurveSpec {
+        name: "brainpoolP384t1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.12",
+        p: concat!(
+            "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b4",
+            "12b1da197fb71123acd3a729901d1a71874700133107ec53"
+        ),
+        a: concat!(
+            "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b4",
+            "12b1da197fb71123acd3a729901d1a71874700133107ec50"
+        ),
+        b: concat!(
+            "7f519eada7bda81bd826dba647910f8c4b9346ed8ccdc64e",
+            "4b1abd11756dce1d2074aa263b88805ced70355a33b471ee"
+        ),
+        gx: concat!(
+            "18de98b02db9a306f2afcd7235f72a819b80ab12ebd65317",
+            "2476fecd462aabffc4ff191b946a5f54d8d0aa2f418808cc"
+        ),
+        gy: concat!(
+            "25ab056962d30651a114afd2755ad336747f93475b7a1fca",
+            "3b88f2b6a208ccfe469408584dc2b2912675bf5b9e582928"
+        ),
+        n: concat!(
+            "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b3",
+            "1f166e6cac0425a7cf3ab6af6b7fc3103b883202e9046565"
+        ),
+        h: "1",
+    },
+    CurveSpec {
+        name: "brainpoolP512r1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.13",
+        p: concat!(
+            "aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca70330871",
+            "7d4d9b009bc66842aecda12ae6a380e62881ff2f2d82c68528aa6056583a48f3"
+        ),
+        a: concat!(
+            "7830a3318b603b89e2327145ac234cc594cbdd8d3df91610a83441caea9863bc",
+            "2ded5d5aa8253aa10a2ef1c98b9ac8b57f1117a72bf2c7b9e7c1ac4d77fc94ca"
+        ),
+        b: concat!(
+            "3df91610a83441caea9863bc2ded5d5aa8253aa10a2ef1c98b9ac8b57f1117a7",
+            "2bf2c7b9e7c1ac4d77fc94cadc083e67984050b75ebae5dd2809bd638016f723"
+        ),
+        gx: concat!(
+            "81aee4bdd82ed9645a21322e9c4c6a9385ed9f70b5d916c1b43b62eef4d0098e",
+            "ff3b1f78e2d0d48d50d1687b93b97d5f7c6d5047406a5e688b352209bcb9f822"
+        ),
+        gy: concat!(
+            "7dde385d566332ecc0eabfa9cf7822fdf209f70024a57b1aa000c55b881f8111",
+            "b2dcde494a5f485e5bca4bd88a2763aed1ca2b2fa8f0540678cd1e0f3ad80892"
+        ),
+        n: concat!(
+            "aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca70330870",
+            "553e5c414ca92619418661197fac10471db1d381085ddaddb58796829ca90069"
+        ),
+        h: "1",
+    },
+    CurveSpec {
+        name: "brainpoolP512t1",
+        aliases: &[],
+        oid: "1.3.36.3.3.2.8.1.1.14",
+        p: concat!(
+            "aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca70330871",
+            "7d4d9b009bc66842aecda12ae6a380e62881ff2f2d82c68528aa6056583a48f3"
+        ),
+        a: concat!(
+            "aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca70330871",
+            "7d4d9b009bc66842aecda12ae6a380e62881ff2f2d82c68528aa6056583a48f0"
+        ),
+        b: concat!(
+            "7cbbbcf9441cfab76e1890e46884eae321f70c0bcb4981527897504bec3e36a6",
+            "2bcdfa2304976540f6450085f2dae145c22553b465763689180ea2571867423e"
+        ),
+        gx: concat!(
+            "640ece5c12788717b9c1ba06cbc2a6feba85842458c56dde9db1758d39c0313d",
+            "82ba51735cdb3ea499aa77a7d6943a64f7a3f25fe26f06b51baa2696fa9035da"
+        ),
+        gy: concat!(
+            "5b534bd595f5af0fa2c892376c84ace1bb4e3019b71634c01131159cae03cee9",
+            "d9932184beef216bd71df2dadf86a627306ecff96dbb8bace198b61e00f8b332"
+        ),
+        n: concat!(
+            "aadd9db8dbe9c48b3fd4e6ae33c9fc07cb308db3b3c9d20ed6639cca70330870",
+            "553e5c414ca92619418661197fac10471db1d381085ddaddb58796829ca90069"
+        ),
+        h: "1",
+    },
+    CurveSpec {
+        name: "secp160k1",
+        aliases: &[],
+        oid: "1.3.132.0.9",
+        p: "fffffffffffffffffffffffffffffffeffffac73",
+        a: "0",
+        b: "7",
+        gx: "3b4c382ce37aa192a4019e763036f4f5dd4d7ebb",
+        gy: "938cf935318fdced6bc28286531733c3f03c4fee",
+        n: "0100000000000000000001b8fa16dfab9aca16b6b3",
+        h: "1",
+    },
+    CurveSpec {
+        name: "secp192k1",
+        aliases: &[],
+        oid: "1.3.132.0.31",
+        p: "fffffffffffffffffffffffffffffffffffffffeffffee37",
+        a: "0",
+        b: "3",
+        gx: "db4ff10ec057e9ae26b07d0280b7f4341da5d1b1eae06c7d",
+        gy: "9b2f2f6d9c5628a7844163d015be86344082aa88d95e2f9d",
+        n: "fffffffffffffffffffffffe26f2fc170f69466a74defd8d",
+        h: "1",
+    },
+    CurveSpec {
+        name: "secp192r1",
+        aliases: &["P-192", "prime192v1", "nistp192"],
+        oid: "1.2.840.10045.3.1.1",
+        p: "fffffffffffffffffffffffffffffffeffffffffffffffff",
+        a: "fffffffffffffffffffffffffffffffefffffffffffffffc",
+        b: "64210519e59c80e70fa7e9ab72243049feb8deecc146b9b1",
+        gx: "188da80eb03090f67cbf20eb43a18800f4ff0afd82ff1012",
+        gy: "07192b95ffc8da78631011ed6b24cdd573f977a11e794811",
+        n: "ffffffffffffffffffffffff99def836146bc9b1b4d22831",
+        h: "1",
+    },
+    CurveSpec {
+        name: "secp224k1",
+        aliases: &[],
+        oid: "1.3.132.0.32",
+        p: "fffffffffffffffffffffffffffffffffffffffffffffffeffffe56d",
+        a: "0",
+        b: "5",
+        gx: "a1455b334df099df30fc28a169a467e9e47075a90f7e650eb6b7a45c",
+        gy: "7e089fed7fba344282cafbd6f7e319f7c0b0bd59e2ca4bdb556d61a5",
+        n: "010000000000000000000000000001dce8d2ec6184caf0a971769fb1f7",
+        h: "1",
+    },
+    CurveSpec {
+        name: "secp224r1",
+        aliases: &["P-224", "nistp224"],
+        oid: "1.3.132.0.33",
+        p: "ffffffffffffffffffffffffffffffff000000000000000000000001",
+        a: "fffffffffffffffffffffffffffffffefffffffffffffffffffffffe",
+        b: "b4050a850c04b3abf54132565044b0b7d7bfd8ba270b39432355ffb4",
+        gx: "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+        gy: "bd376388b5f723fb4c22dfe6cd4375a05a07476444d5819985007e34",
+        n: "ffffffffffffffffffffffffffff16a2e0b8f03e13dd29455c5c2a3d",
+        h: "1",
+    },
+    CurveSpec {
+        name: "secp256k1",
+        aliases: &[],
+        oid: "1.3.132.0.10",
+        p: "fffffffffffffffffffffffffffffffffffffffffffffffffffffffefffffc2f",
+        a: "0",
+        b: "7",
+        gx: "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        gy: "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+        n: "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364141",
+        h: "1",
+    },
+    CurveSpec {
+        name: "secp256r1",
+        aliases: &["P-256", "prime256v1", "nistp256"],
+        oid: "1.2.840.10045.3.1.7",
+        p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        a: "ffffffff00000001000000000000000000000000fffffffffffffffffffffffc",
+        b: "5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b",
+        gx: "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296",
+        gy: "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5",
+        n: "ffffffff00000000ffffffffffffffffbce6faada7179e84f3b9cac2fc632551",
+        h: "1",
+    },
+    CurveSpec {
+        name: "secp384r1",
+        aliases: &["P-384", "nistp384"],
+        oid: "1.3.132.0.34",
+        p: concat!(
+            "ffffffffffffffffffffffffffffffffffffffffffffffff",
+            "fffffffffffffffeffffffff0000000000000000ffffffff"
+        ),
+        a: concat!(
+            "ffffffffffffffffffffffffffffffffffffffffffffffff",
+            "fffffffffffffffeffffffff0000000000000000fffffffc"
+        ),
+        b: concat!(
+            "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe814112",
+            "0314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef"
+        ),
+        gx: concat!(
+            "aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b98",
+            "59f741e082542a385502f25dbf55296c3a545e3872760ab7"
+        ),
+        gy: concat!(
+            "3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147c",
+            "e9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f"
+        ),
+        n: concat!(
+            "ffffffffffffffffffffffffffffffffffffffffffffffff",
+            "c7634d81f4372ddf581a0db248b0a77aecec196accc52973"
+        ),
+        h: "1",
+    },
+    CurveSpec {
+        name: "secp521r1",
+        aliases: &["P-521", "nistp521"],
+        oid: "1.3.132.0.35",
+        p: concat!(
+            "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff"
+        ),
+        a: concat!(
+            "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffc"
+        ),
+        b: concat!(
+            "0051953eb9618e1c9a1f929a21a0b68540eea2da725b99b315f3b8b489918ef109",
+            "e156193951ec7e937b1652c0bd3bb1bf073573df883d2c34f1ef451fd46b503f00"
+        ),
+        gx: concat!(
+            "00c6858e06b70404e9cd9e3ecb662395b4429c648139053fb521f828af606b4d3d",
+            "baa14b5e77efe75928fe1dc127a2ffa8de3348b3c1856a429bf97e7e31c2e5bd66"
+        ),
+        gy: concat!(
+            "011839296a789a3bc0045c8a5fb42c7d1bd998f54449579b446817afbd17273e66",
+            "2c97ee72995ef42640c550b9013fad0761353c7086a272c24088be94769fd16650"
+        ),
+        n: concat!(
+            "01ffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffff",
+            "fa51868783bf2f966b7fcc0148f709a5d03bb5c9b8899c47aebb6fb71e91386409"
+        ),
+        h: "1",
+    },
+];
+
+// GLV endomorphisms of the built-in a = 0 Koblitz curves, checked against the
+// curves by Curve::with_endomorphism in the tests
+struct EndomorphismSpec {
+    curve: &'static str,
+    beta: &'static str,
+    lambda: &'static str,
+    basis: [(&'static str, &'static str); 2],
+}
+
+impl EndomorphismSpec {
+    fn to_endomorphism(&self) -> Endomorphism {
+        Endomorphism {
+            beta: hex(self.beta),
+            lambda: hex(self.lambda),
+            basis: self.basis.map(|(a, b)| (hex(a), hex(b))),
+        }
+    }
+}
+
+const ENDOMORPHISMS: &[EndomorphismSpec] = &[
+    EndomorphismSpec {
+        curve: "secp160k1",
+        beta: "9ba48cba5ebcb9b6bd33b92830b2a2e0e192f10a",
+        lambda: "c39c6c3b3a36d7701b9c71a1f5804ae5d0003f4",
+        basis: [
+            ("9162fbe73984472a0a9e", "-96341f1138933bc2f505"),
+            ("127971af8721782ecffa3", "9162fbe73984472a0a9e"),
+        ],
+    },
+    EndomorphismSpec {
+        curve: "secp192k1",
+        beta: "447a96e6c647963e2f7809feaab46947f34b0aa3ca0bba74",
+        lambda: "c27b0d93eddc7284b0c2ae9813318686dbb7a0ea73692cdb",
+        basis: [
+            ("b3fb3400dec5c4adceb8655c", "-71169be7330b3038edb025f1"),
+            ("71169be7330b3038edb025f1", "12511cfe811d0f4e6bc688b4d"),
+        ],
+    },
+    EndomorphismSpec {
+        curve: "secp224k1",
+        beta: "1f178ffa4b17c89e6f73aece2aad57af4c0a748b63c830947b27e04",
+        lambda: "9f232defb3b343f41911103d422bcc75342913534b55766d0a016a6e",
+        basis: [
+            (
+                "b8adf1378a6eb73409fa6c9c637d",
+                "-6b8cf07d4ca75c88957d9d670591",
             ),
-            n: BigInt::from_str_radix("e95e4a5f737059dc60df5991d45029409e60fc09", 16).unwrap(),
-            h: BigInt::from_str_radix("1", 16).unwrap(),
-        },
-        "brainpoolp192r1" => CurveParams {
-            p: BigInt::from_str_radix("c302f41d932a36cda7a3463093d18db78fce476de1a86297", 16)
-                .unwrap(),
-            a: BigInt::from_str_radix("6a91174076b1e0e19c39c031fe8685c1cae040e5c69a28ef", 16)
-                .unwrap(),
-            b: BigInt::from_str_radix("469a28ef7c28cca3dc721d044f4496bcca7ef4146fbf25c9", 16)
-                .unwrap(),
-            g: (
-                BigInt::from_str_radix("c0a0647eaab6a48753b033c56cb0f0900a2f5c4853375fd6", 16)
-                    .unwrap(),
-                BigInt::from_str_radix("14b690866abd5bb88b5f4828c1490002e6773fa2fa299b8f", 16)
-                    .unwrap(),
+            (
+                "6b8cf07d4ca75c88957d9d670591",
+                "1243ae1b4d71613bc9f780a03690e",
             ),
-            n: BigInt::from_str_radix("c302f41d932a36cda7a3462f9e9e916b5be8f1029ac4acc1", 16)
-                .unwrap(),
-            h: BigInt::from_str_radix("1", 16).unwrap(),
-        },
-        "secp192r1" => CurveParams {
-            p: BigInt::from_str_radix("fffffffffffffffffffffffffffffffeffffffffffffffff", 16)
-                .unwrap(),
-            a: BigInt::from_str_radix("fffffffffffffffffffffffffffffffefffffffffffffffc", 16)
-                .unwrap(),
-            b: BigInt::from_str_radix("64210519e59c80e70fa7e9ab72243049feb8deecc146b9b1", 16)
-                .unwrap(),
+        ],
+    },
+    EndomorphismSpec {
+        curve: "secp256k1",
+        beta: "7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee",
+        lambda: "5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72",
+        basis: [
+            (
+                "3086d221a7d46bcde86c90e49284eb15",
+                "-e4437ed6010e88286f547fa90abfe4c3",
+            ),
+            (
+                "114ca50f7a8e2f3f657c1108d9d44cfd8",
+                "3086d221a7d46bcde86c90e49284eb15",
+            ),
+        ],
+    },
+];
+
+// Curve registered at runtime, e.g. loaded from a definition file
+struct CustomCurve {
+    curve: Curve,
+    aliases: Vec<String>,
+    oid: Option<String>,
+}
+
+impl CustomCurve {
+    fn matches(&self, name: &str) -> bool {
+        self.curve.name.eq_ignore_ascii_case(name)
+            || self
+                .aliases
+                .iter()
+                .any(|alias| alias.eq_ignore_ascii_case(name))
+    }
+}
+
+static CUSTOM_CURVES: RwLock<Vec<CustomCurve>> = RwLock::new(Vec::new());
+
+fn custom_curves() -> RwLockReadGuard<'static, Vec<CustomCurve>> {
+    CUSTOM_CURVES.read().unwrap_or_else(|e| e.into_inner())
+}
+
+// Canonical names of all known curves, built-in ones first
+pub fn list_curves() -> Vec<String> {
+    CURVES
+        .iter()
+        .map(|spec| String::from(spec.name))
+        .chain(
+            custom_curves()
+                .iter()
+                .map(|custom| custom.curve.name.clone()),
+        )
+        .collect()
+}
+
+// Looks a curve up by its canonical name or one of its aliases, ignoring case
+pub fn get_curve(name: &str) -> Result<Curve, Error> {
+    if let Some(spec) = CURVES.iter().find(|spec| spec.matches(name)) {
+        return Ok(spec.to_curve());
+    }
+    custom_curves()
+        .iter()
+        .find(|custom| custom.matches(name))
+        .map(|custom| custom.curve.clone())
+        .ok_or(Error::UnknownCurve)
+}
+
+// Looks a curve up by its ASN.1 object identifier in dotted form, e.g. "1.2.840.10045.3.1.7"
+pub fn get_curve_by_oid(oid: &str) -> Result<Curve, Error> {
+    let oid = oid.trim();
+    if let Some(spec) = CURVES.iter().find(|spec| spec.oid == oid) {
+        return Ok(spec.to_curve());
+    }
+    custom_curves()
+        .iter()
+        .find(|custom| custom.oid.as_deref() == Some(oid))
+        .map(|custom| custom.curve.clone())
+        .ok_or(Error::UnknownCurve)
+}
+
+// Object identifier of a curve given by name or alias
+pub fn curve_oid(name: &str) -> Option<String> {
+    if let Some(spec) = CURVES.iter().find(|spec| spec.matches(name)) {
+        return Some(String::from(spec.oid));
+    }
+    custom_curves()
+        .iter()
+        .find(|custom| custom.matches(name))
+        .and_then(|custom| custom.oid.clone())
+}
+
+// Aliases of a curve given by any of its names
+pub fn curve_aliases(name: &str) -> Option<Vec<String>> {
+    if let Some(spec) = CURVES.iter().find(|spec| spec.matches(name)) {
+        return Some(
+            spec.aliases
+                .iter()
+                .map(|alias| String::from(*alias))
+                .collect(),
+        );
+    }
+    custom_curves()
+        .iter()
+        .find(|custom| custom.matches(name))
+        .map(|custom| custom.aliases.clone())
+}
+
+// Custom curves must pass the same structural checks as the built-in ones. The
+// security checks are not enforced since toy curves are a legitimate use.
+fn check_curve(curve: &Curve) -> Result<(), Error> {
+    let report = curve.validate();
+    if !report.is_well_formed() {
+        return Err(Error::InvalidCurve(report));
+    }
+    Ok(())
+}
+
+// Registers a curve under its name and the given aliases after validating it
+pub fn register_curve(
+    curve: Curve,
+    aliases: Vec<String>,
+    oid: Option<String>,
+) -> Result<(), Error> {
+    register_curves(vec![CustomCurve {
+        curve,
+        aliases,
+        oid,
+    }])
+    .map(|_| ())
+}
+
+// Validates every curve before registering any of them, so a bad file leaves the registry untouched
+fn register_curves(curves: Vec<CustomCurve>) -> Result<Vec<String>, Error> {
+    for custom in &curves {
+        check_curve(&custom.curve)?;
+    }
+
+    let mut registered = CUSTOM_CURVES.write().unwrap_or_else(|e| e.into_inner());
+    let mut seen: Vec<&str> = Vec::new();
+    for custom in &curves {
+        let names = std::iter::once(custom.curve.name.as_str())
+            .chain(custom.aliases.iter().map(String::as_str))
+            .chain(custom.oid.as_deref());
+        for name in names {
+            let taken = CURVES
+                .iter()
+                .any(|spec| spec.matches(name) || spec.oid == name)
+                || registered
+                    .iter()
+                    .any(|other| other.matches(name) || other.oid.as_deref() == Some(name))
+                || seen.iter().any(|other| other.eq_ignore_ascii_case(name));
+            if taken {
+                return Err(Error::DuplicateCurve);
+            }
+            seen.push(name);
+        }
+    }
+
+    let names = curves
+        .iter()
+        .map(|custom| custom.curve.name.clone())
+        .collect();
+    registered.extend(curves);
+    Ok(names)
+}
+
+pub enum CurveFormat {
+    Toml,
+    Json,
+}
+
+#[derive(Deserialize)]
+struct CurveFile {
+    curves: Vec<CurveDefinition>,
+}
+
+#[derive(Deserialize)]
+struct CurveDefinition {
+    name: String,
+    #[serde(default)]
+    aliases: Vec<String>,
+    oid: Option<String>,
+    p: Number,
+    a: Number,
+    b: Number,
+    g: (Number, Number),
+    n: Number,
+    #[serde(default = "Number::one")]
+    h: Number,
+}
+
+// Domain parameter in a curve file: an integer, or a string holding a
+// decimal or 0x-prefixed hexadecimal number (for values wider than 64 bits)
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Number {
+    Int(i64),
+    Str(String),
+}
+
+impl Number {
+    fn one() -> Self {
+        Number::Int(1)
+    }
+
+    fn parse(&self) -> Result<BigInt, Error> {
+        let parsed = match self {
+            Number::Int(i) => Some(BigInt::from(*i)),
+            Number::Str(s) => {
+                let s = s.trim();
+                let (negative, digits) = match s.strip_prefix('-') {
+                    Some(digits) => (true, digits),
+                    None => (false, s),
+                };
+                let value = match digits.strip_prefix("0x").or(digits.strip_prefix("0X")) {
+                    Some(hex) => BigInt::from_str_radix(hex, 16).ok(),
+                    None => BigInt::from_str_radix(digits, 10).ok(),
+                };
+                value.map(|v| if negative { -v } else { v })
+            }
+        };
+        parsed.ok_or_else(|| Error::MalformedCurveFile(String::from("malformed number")))
+    }
+}
+
+impl CurveDefinition {
+    fn into_custom_curve(self) -> Result<CustomCurve, Error> {
+        let p = self.p.parse()?;
+        // Coordinates are reduced mod p below, which needs a modulus of at least 2
+        if p < BigInt::from(2) {
+            return Err(Error::MalformedCurveFile(String::from(
+                "p must be at least 2",
+            )));
+        }
+        let sub_group = SubGroup {
             g: (
-                BigInt::from_str_radix("188da80eb03090f67cbf20eb43a18800f4ff0afd82ff1012", 16)
-                    .unwrap(),
-                BigInt::from_str_radix("07192b95ffc8da78631011ed6b24cdd573f977a11e794811", 16)
-                    .unwrap(),
+                self.g.0.parse()?.rem_euclid(&p),
+                self.g.1.parse()?.rem_euclid(&p),
             ),
-            n: BigInt::from_str_radix("ffffffffffffffffffffffff99def836146bc9b1b4d22831", 16)
-                .unwrap(),
-            h: BigInt::from_str_radix("1", 16).unwrap(),
-        },
-        _ => return Err("Unknown elliptic curve name"),
+            n: self.n.parse()?,
+            h: self.h.parse()?,
+            p: p.clone(),
+        };
+        let curve = Curve::new(
+            self.a.parse()?.rem_euclid(&p),
+            self.b.parse()?.rem_euclid(&p),
+            sub_group,
+            self.name,
+        );
+        Ok(CustomCurve {
+            curve,
+            aliases: self.aliases,
+            oid: self.oid,
+        })
+    }
+}
+
+// Parses a list of curve definitions and registers them alongside the built-in curves.
+// Returns the names of the newly registered curves.
+pub fn load_curves_from_str(contents: &str, format: CurveFormat) -> Result<Vec<String>, Error> {
+    let file: CurveFile = match format {
+        CurveFormat::Toml => toml::from_str(contents)
+            .map_err(|e| Error::MalformedCurveFile(e.message().to_string()))?,
+        CurveFormat::Json => {
+            serde_json::from_str(contents).map_err(|e| Error::MalformedCurveFile(e.to_string()))?
+        }
     };
+    let curves = file
+        .curves
+        .into_iter()
+        .map(CurveDefinition::into_custom_curve)
+        .collect::<Result<Vec<_>, _>>()?;
+    register_curves(curves)
+}
 
-    let sub_group = SubGroup {
-        p: curve_params.p,
-        g: curve_params.g,
-        n: curve_params.n,
-        h: curve_params.h,
+// Loads curve definitions from a .toml or .json file
+pub fn load_curves(path: impl AsRef<Path>) -> Result<Vec<String>, Error> {
+    let path = path.as_ref();
+    let format = match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("toml") => CurveFormat::Toml,
+        Some(ext) if ext.eq_ignore_ascii_case("json") => CurveFormat::Json,
+        _ => return Err(Error::UnsupportedFormat),
     };
+    let contents = fs::read_to_string(path)?;
+    load_curves_from_str(&contents, format)
+}
 
-    let curve = Curve {
-        a: curve_params.a,
-        b: curve_params.b,
-        field: sub_group,
-        name: String::from(name),
+// Isomorphism constant Z (RFC 5639, section 3) relating brainpoolPxxxr1 to its
+// twist brainpoolPxxxt1 via (x, y) -> (Z^2·x, Z^3·y)
+pub fn twist_constant(name: &str) -> Result<BigInt, Error> {
+    let name = name.to_lowercase();
+    let bits = name
+        .strip_prefix("brainpoolp")
+        .and_then(|rest| rest.strip_suffix("r1").or_else(|| rest.strip_suffix("t1")))
+        .and_then(|bits| bits.parse::<u32>().ok());
+    let z = match bits {
+        Some(160) => hex("24dbff5dec9b986bbfe5295a29bfbae45e0f5d0b"),
+        Some(192) => hex("1b6f5cc8db4dc7af19458a9cb80dc2295e5eb9c3732104cb"),
+        Some(224) => hex("2df271e14427a346910cf7a2e6cfa7b3f484e5c2cce1c8b730e28b3f"),
+        Some(256) => hex("3e2d4bd9597b58639ae7aa669cab9837cf5cf20a2c852d10f655668dfc150ef0"),
+        Some(320) => hex(concat!(
+            "15f75caf668077f7e85b42eb01f0a81ff56ecd61",
+            "91d55cb82b7d861458a18fefc3e5ab7496f3c7b1"
+        )),
+        Some(384) => hex(concat!(
+            "41dfe8dd399331f7166a66076734a89cd0d2bcdb7d068e44",
+            "e1f378f41ecbae97d2d63dbc87bccddccc5da39e8589291c"
+        )),
+        Some(512) => hex(concat!(
+            "12ee58e6764838b69782136f0f2d3ba06e27695716054092e60a80bedb212b64",
+            "e585d90bce13761f85c3f1d2a64e3be8fea2220f01eba5eeb0f35dbd29d922ab"
+        )),
+        _ => return Err(Error::NoTwist),
     };
+    Ok(z)
+}
+
+// Maps a point on brainpoolPxxxr1 to the corresponding point on brainpoolPxxxt1
+pub fn to_twisted<'c>(point: &Point, twisted: &'c Curve) -> Result<Point<'c>, Error> {
+    let z = twist_constant(&twisted.name)?;
+    point.isomorphism(twisted, &z)
+}
 
-    Ok(curve)
+// Maps a point on brainpoolPxxxt1 back to the corresponding point on brainpoolPxxxr1
+pub fn from_twisted<'c>(point: &Point, curve: &'c Curve) -> Result<Point<'c>, Error> {
+    let z = twist_constant(&curve.name)?;
+    let z_inv = z.modinv(&curve.field.p).ok_or(Error::NonInvertible)?;
+    point.isomorphism(curve, &z_inv)
 }
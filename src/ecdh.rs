@@ -0,0 +1,93 @@
+use crate::error::Error;
+use crate::utils::hmac;
+use hmac::digest::core_api::BlockSizeUser;
+use hmac::digest::Digest;
+use std::fmt;
+
+#[derive(Clone, PartialEq, Eq)]
+// Raw ECDH shared secret: the x-coordinate of d·Q as a fixed-length
+// big-endian octet string sized to the curve's prime p
+pub struct SharedSecret {
+    bytes: Vec<u8>,
+}
+
+impl SharedSecret {
+    pub fn new(bytes: Vec<u8>) -> Self {
+        SharedSecret { bytes }
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    // Derives len bytes of key material with the ANSI X9.63 KDF
+    pub fn derive_x963<D: Digest>(&self, shared_info: &[u8], len: usize) -> Result<Vec<u8>, Error> {
+        x963_kdf::<D>(&self.bytes, shared_info, len)
+    }
+
+    // Derives len bytes of key material with HKDF (RFC 5869)
+    pub fn derive_hkdf<D: Digest + BlockSizeUser>(
+        &self,
+        salt: &[u8],
+        info: &[u8],
+        len: usize,
+    ) -> Result<Vec<u8>, Error> {
+        hkdf::<D>(&self.bytes, salt, info, len)
+    }
+}
+
+impl fmt::Debug for SharedSecret {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SharedSecret {{ .. }}")
+    }
+}
+
+// ANSI X9.63 key derivation (SEC 1, section 3.6.1)
+pub fn x963_kdf<D: Digest>(z: &[u8], shared_info: &[u8], len: usize) -> Result<Vec<u8>, Error> {
+    let hlen = <D as Digest>::output_size();
+    if len as u64 >= hlen as u64 * u32::MAX as u64 {
+        return Err(Error::InvalidKeyLength);
+    }
+    let mut key = Vec::with_capacity(len + hlen);
+    let mut counter: u32 = 1;
+    while key.len() < len {
+        let mut hasher = D::new();
+        hasher.update(z);
+        hasher.update(counter.to_be_bytes());
+        hasher.update(shared_info);
+        key.extend_from_slice(&hasher.finalize());
+        counter += 1;
+    }
+    key.truncate(len);
+    Ok(key)
+}
+
+// HMAC-based extract-and-expand key derivation (RFC 5869)
+pub fn hkdf<D: Digest + BlockSizeUser>(
+    ikm: &[u8],
+    salt: &[u8],
+    info: &[u8],
+    len: usize,
+) -> Result<Vec<u8>, Error> {
+    let hlen = <D as Digest>::output_size();
+    if len > 255 * hlen {
+        return Err(Error::InvalidKeyLength);
+    }
+    let salt = if salt.is_empty() {
+        vec![0; hlen]
+    } else {
+        salt.to_vec()
+    };
+    let prk = hmac::<D>(&salt, &[ikm]);
+
+    let mut okm = Vec::with_capacity(len + hlen);
+    let mut t = Vec::new();
+    let mut counter: u8 = 1;
+    while okm.len() < len {
+        t = hmac::<D>(&prk, &[&t, info, &[counter]]);
+        okm.extend_from_slice(&t);
+        counter = counter.wrapping_add(1);
+    }
+    okm.truncate(len);
+    Ok(okm)
+}
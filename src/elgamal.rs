@@ -0,0 +1,70 @@
+use crate::curve::Curve;
+use crate::error::Error;
+use crate::point::Point;
+use num_bigint::{BigInt, Sign};
+
+// Number of x-coordinates tried per message when embedding it in a point (Koblitz's method)
+const ENCODING_TRIES: u32 = 256;
+
+#[derive(Clone, PartialEq, Eq, Debug)]
+// EC-ElGamal ciphertext (c1, c2) = (k·G, M + k·Q)
+pub struct Ciphertext<'c> {
+    pub c1: Point<'c>,
+    pub c2: Point<'c>,
+}
+
+impl<'c> Ciphertext<'c> {
+    pub fn new(c1: Point<'c>, c2: Point<'c>) -> Self {
+        Ciphertext { c1, c2 }
+    }
+
+    // Homomorphic addition: decrypts to the sum of the two plaintext points
+    pub fn add(&self, other: &Ciphertext<'c>) -> Result<Ciphertext<'c>, Error> {
+        if self.c1.curve() != other.c1.curve() {
+            return Err(Error::CurveMismatch);
+        }
+        let c1 = Point::add(&self.c1, &other.c1)?;
+        let c2 = Point::add(&self.c2, &other.c2)?;
+        Ok(Ciphertext { c1, c2 })
+    }
+}
+
+// Longest byte string that encode_message can embed in a point of this curve
+pub fn max_message_len(curve: &Curve) -> usize {
+    // x = m·K + j must stay below p, and the 0x01 prefix takes one bit of m
+    let usable_bits = curve
+        .field
+        .p
+        .bits()
+        .saturating_sub(2 + ENCODING_TRIES.ilog2() as u64);
+    (usable_bits / 8) as usize
+}
+
+// Embeds msg in the x-coordinate of a curve point as x = m·K + j, where m is
+// msg prefixed with 0x01 (to keep leading zero bytes) and j < K is the first
+// offset for which x^3 + ax + b is a square
+pub fn encode_message<'c>(curve: &'c Curve, msg: &[u8]) -> Result<Point<'c>, Error> {
+    if msg.len() > max_message_len(curve) {
+        return Err(Error::MessageTooLong);
+    }
+    let m = BigInt::from_bytes_be(Sign::Plus, &[&[0x01], msg].concat());
+    for j in 0..ENCODING_TRIES {
+        let x = &m * ENCODING_TRIES + j;
+        if let Ok(y) = curve.y_squared(&curve.base_field().element(&x)).sqrt() {
+            return Point::new(curve, x, y.to_bigint());
+        }
+    }
+    Err(Error::InvalidEncoding)
+}
+
+// Recovers the byte string embedded by encode_message
+pub fn decode_message(point: &Point) -> Result<Vec<u8>, Error> {
+    if point.is_infinity() {
+        return Err(Error::InvalidEncoding);
+    }
+    let (_, bytes) = (&point.x / ENCODING_TRIES).to_bytes_be();
+    match bytes.split_first() {
+        Some((0x01, msg)) => Ok(msg.to_vec()),
+        _ => Err(Error::InvalidEncoding),
+    }
+}
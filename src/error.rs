@@ -0,0 +1,77 @@
+use crate::curve::ValidationReport;
+use std::fmt;
+use std::io;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    NotOnCurve,
+    CurveMismatch,
+    NoKeyMaterial,
+    MissingPrivateKey,
+    NonInvertible,
+    NonceExhausted,
+    NotAResidue,
+    InvalidScalar,
+    InvalidWindowWidth,
+    NoTerms,
+    PointAtInfinity,
+    NotInSubgroup,
+    InvalidKeyLength,
+    MessageTooLong,
+    InvalidEncoding,
+    UnknownCurve,
+    NoTwist,
+    InvalidEndomorphism,
+    DuplicateCurve,
+    UnsupportedFormat,
+    UnsupportedBackend,
+    InvalidModulus,
+    MalformedCurveFile(String),
+    Io(io::ErrorKind),
+    InvalidCurve(ValidationReport),
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let msg = match self {
+            Error::NotOnCurve => "point is not on the curve",
+            Error::CurveMismatch => "points belong to different curves",
+            Error::NoKeyMaterial => "neither a private key nor a public key was provided",
+            Error::MissingPrivateKey => "keypair has no private key",
+            Error::NonInvertible => "value has no modular inverse",
+            Error::NonceExhausted => "nonce generator produced no usable nonce",
+            Error::NotAResidue => "value is not a quadratic residue",
+            Error::InvalidScalar => "scalar is out of range",
+            Error::InvalidWindowWidth => "window width is out of the supported range",
+            Error::NoTerms => "multi-scalar multiplication needs at least one term",
+            Error::PointAtInfinity => "point at infinity",
+            Error::NotInSubgroup => "point is not in the prime-order subgroup",
+            Error::InvalidKeyLength => "requested key length is not supported",
+            Error::MessageTooLong => "message is too long to encode as a curve point",
+            Error::InvalidEncoding => "invalid encoding",
+            Error::UnknownCurve => "unknown elliptic curve",
+            Error::NoTwist => "curve has no RFC 5639 twist",
+            Error::InvalidEndomorphism => "endomorphism parameters do not match the curve",
+            Error::DuplicateCurve => "curve name, alias or OID is already registered",
+            Error::UnsupportedFormat => "curve files must have a .toml or .json extension",
+            Error::UnsupportedBackend => "field backend does not support this modulus",
+            Error::InvalidModulus => "field modulus must be at least 2",
+            Error::MalformedCurveFile(reason) => {
+                return write!(f, "malformed curve file: {}", reason)
+            }
+            Error::Io(kind) => return write!(f, "could not read curve file: {}", kind),
+            Error::InvalidCurve(report) => {
+                return write!(f, "invalid curve domain parameters: {}", report)
+            }
+        };
+        write!(f, "{}", msg)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Error::Io(err.kind())
+    }
+}
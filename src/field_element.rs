@@ -0,0 +1,546 @@
+use crate::error::Error;
+use crate::limbs::LimbField;
+use crate::utils::{legendre, modsqrt};
+use num_bigint::BigInt;
+use num_traits::{Euclid, One, Zero};
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+// How a PrimeField stores its elements and reduces products
+pub enum FieldBackend {
+    // Montgomery multiplication on BigInts, for any modulus
+    BigInt,
+    // Montgomery multiplication on fixed-width limbs, for odd moduli up to 576 bits
+    Montgomery,
+    // NIST fast reduction on fixed-width limbs, for P-192, P-224, P-256 and P-384
+    Solinas,
+    // Folding reduction on fixed-width limbs for p = 2^(64·limbs) - c with c < 2^64,
+    // such as the secp256k1 prime
+    PseudoMersenne,
+}
+
+impl FieldBackend {
+    // Fastest backend supporting p
+    pub fn best_for(p: &BigInt) -> FieldBackend {
+        [
+            FieldBackend::Solinas,
+            FieldBackend::PseudoMersenne,
+            FieldBackend::Montgomery,
+        ]
+        .into_iter()
+        .find(|backend| Backend::new(p, *backend).is_some())
+        .unwrap_or(FieldBackend::BigInt)
+    }
+}
+
+// Field operations on a backend's raw representation, whose values are always
+// reduced below p
+pub(crate) trait FieldArithmetic {
+    type Repr;
+
+    // Converts x, which must already be in [0, p), into the representation
+    fn encode(&self, x: &BigInt) -> Self::Repr;
+    fn decode(&self, a: &Self::Repr) -> BigInt;
+    fn zero(&self) -> Self::Repr;
+    fn is_zero(&self, a: &Self::Repr) -> bool;
+    fn add(&self, a: &Self::Repr, b: &Self::Repr) -> Self::Repr;
+    fn sub(&self, a: &Self::Repr, b: &Self::Repr) -> Self::Repr;
+    fn mul(&self, a: &Self::Repr, b: &Self::Repr) -> Self::Repr;
+}
+
+#[derive(Clone)]
+// Montgomery arithmetic on BigInts, where elements are stored as x·R mod p with
+// R = 2^(64·limbs) the smallest power of the word size above p. Even moduli
+// cannot use Montgomery form and fall back to R = 1, i.e. plain reduction.
+struct BigIntField {
+    p: BigInt,
+    r_bits: u64,
+    r_mask: BigInt,
+    // -p^-1 mod R
+    p_inv: BigInt,
+    // R^2 mod p, for converting into Montgomery form
+    r2: BigInt,
+}
+
+impl BigIntField {
+    fn new(p: &BigInt) -> Self {
+        let r_bits = if p.bit(0) {
+            p.bits().div_ceil(64) * 64
+        } else {
+            0
+        };
+        let r = BigInt::one() << r_bits;
+        let p_inv = match p.modinv(&r) {
+            Some(inv) if r_bits > 0 => &r - inv,
+            _ => BigInt::zero(),
+        };
+        let r2 = if p.is_zero() {
+            BigInt::zero()
+        } else {
+            (&r * &r) % p
+        };
+        BigIntField {
+            r_mask: r - 1,
+            p: p.clone(),
+            r_bits,
+            p_inv,
+            r2,
+        }
+    }
+
+    // Montgomery reduction: t·R^-1 mod p for 0 <= t < p·R
+    fn redc(&self, t: BigInt) -> BigInt {
+        if self.r_bits == 0 {
+            return t % &self.p;
+        }
+        let m = ((&t & &self.r_mask) * &self.p_inv) & &self.r_mask;
+        let u: BigInt = (t + m * &self.p) >> self.r_bits;
+        if u >= self.p {
+            u - &self.p
+        } else {
+            u
+        }
+    }
+}
+
+impl FieldArithmetic for BigIntField {
+    type Repr = BigInt;
+
+    fn encode(&self, x: &BigInt) -> BigInt {
+        self.redc(x * &self.r2)
+    }
+
+    fn decode(&self, a: &BigInt) -> BigInt {
+        self.redc(a.clone())
+    }
+
+    fn zero(&self) -> BigInt {
+        BigInt::zero()
+    }
+
+    fn is_zero(&self, a: &BigInt) -> bool {
+        a.is_zero()
+    }
+
+    fn add(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        let sum = a + b;
+        if sum >= self.p {
+            sum - &self.p
+        } else {
+            sum
+        }
+    }
+
+    fn sub(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        if a >= b {
+            a - b
+        } else {
+            a + &self.p - b
+        }
+    }
+
+    fn mul(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        self.redc(a * b)
+    }
+}
+
+#[derive(Clone)]
+enum Backend {
+    BigInt(BigIntField),
+    Limbs3(LimbField<3>),
+    Limbs4(LimbField<4>),
+    Limbs5(LimbField<5>),
+    Limbs6(LimbField<6>),
+    Limbs8(LimbField<8>),
+    Limbs9(LimbField<9>),
+}
+
+#[derive(Clone, PartialEq, Eq)]
+enum Repr {
+    BigInt(BigInt),
+    Limbs3([u64; 3]),
+    Limbs4([u64; 4]),
+    Limbs5([u64; 5]),
+    Limbs6([u64; 6]),
+    Limbs8([u64; 8]),
+    Limbs9([u64; 9]),
+}
+
+// Swaps the limbs of a and b through a mask derived from choice, without branching
+fn conditional_swap_limbs<const N: usize>(a: &mut [u64; N], b: &mut [u64; N], choice: bool) {
+    let mask = (choice as u64).wrapping_neg();
+    for (x, y) in a.iter_mut().zip(b.iter_mut()) {
+        let t = mask & (*x ^ *y);
+        *x ^= t;
+        *y ^= t;
+    }
+}
+
+impl Repr {
+    fn conditional_swap(&mut self, other: &mut Repr, choice: bool) {
+        match (self, other) {
+            // BigInts vary in length and cannot be swapped without a branch; only the
+            // limb backends are constant time
+            (Repr::BigInt(a), Repr::BigInt(b)) => {
+                if choice {
+                    std::mem::swap(a, b)
+                }
+            }
+            (Repr::Limbs3(a), Repr::Limbs3(b)) => conditional_swap_limbs(a, b, choice),
+            (Repr::Limbs4(a), Repr::Limbs4(b)) => conditional_swap_limbs(a, b, choice),
+            (Repr::Limbs5(a), Repr::Limbs5(b)) => conditional_swap_limbs(a, b, choice),
+            (Repr::Limbs6(a), Repr::Limbs6(b)) => conditional_swap_limbs(a, b, choice),
+            (Repr::Limbs8(a), Repr::Limbs8(b)) => conditional_swap_limbs(a, b, choice),
+            (Repr::Limbs9(a), Repr::Limbs9(b)) => conditional_swap_limbs(a, b, choice),
+            _ => unreachable!("operands share their field's backend"),
+        }
+    }
+}
+
+impl Backend {
+    fn new(p: &BigInt, backend: FieldBackend) -> Option<Backend> {
+        fn limbs<const N: usize>(p: &BigInt, backend: FieldBackend) -> Option<LimbField<N>> {
+            match backend {
+                FieldBackend::BigInt => None,
+                FieldBackend::Montgomery => LimbField::montgomery(p),
+                FieldBackend::Solinas => LimbField::solinas(p),
+                FieldBackend::PseudoMersenne => LimbField::pseudo_mersenne(p),
+            }
+        }
+        if backend == FieldBackend::BigInt {
+            return Some(Backend::BigInt(BigIntField::new(p)));
+        }
+        // Limb counts are instantiated sparsely; 7-limb moduli use 8 limbs
+        match p.bits().div_ceil(64) {
+            0..=3 => limbs(p, backend).map(Backend::Limbs3),
+            4 => limbs(p, backend).map(Backend::Limbs4),
+            5 => limbs(p, backend).map(Backend::Limbs5),
+            6 => limbs(p, backend).map(Backend::Limbs6),
+            7 | 8 => limbs(p, backend).map(Backend::Limbs8),
+            9 => limbs(p, backend).map(Backend::Limbs9),
+            _ => None,
+        }
+    }
+
+    fn kind(&self) -> FieldBackend {
+        match self {
+            Backend::BigInt(_) => FieldBackend::BigInt,
+            Backend::Limbs3(f) => f.kind(),
+            Backend::Limbs4(f) => f.kind(),
+            Backend::Limbs5(f) => f.kind(),
+            Backend::Limbs6(f) => f.kind(),
+            Backend::Limbs8(f) => f.kind(),
+            Backend::Limbs9(f) => f.kind(),
+        }
+    }
+}
+
+// Calls a FieldArithmetic method on the backend with the operands' representations
+// of the same width. $wrap maps the result, e.g. back into a Repr.
+macro_rules! dispatch {
+    ($backend:expr, $wrap:ident, $op:ident $args:tt) => {
+        dispatch!(@arms $backend, $wrap, $op $args;
+            BigInt, Limbs3, Limbs4, Limbs5, Limbs6, Limbs8, Limbs9)
+    };
+    (@arms $backend:expr, $wrap:ident, $op:ident $args:tt; $($variant:ident),*) => {
+        match $backend {
+            $(Backend::$variant(f) => {
+                dispatch!(@wrap $wrap, $variant, dispatch!(@call f, $op, $variant, $args))
+            })*
+        }
+    };
+    (@call $f:ident, $op:ident, $variant:ident, ($($arg:expr),*)) => {
+        $f.$op($(match $arg {
+            Repr::$variant(a) => a,
+            _ => unreachable!("operands share their field's backend"),
+        }),*)
+    };
+    (@wrap repr, $variant:ident, $e:expr) => {
+        Repr::$variant($e)
+    };
+    (@wrap plain, $variant:ident, $e:expr) => {
+        $e
+    };
+}
+
+#[derive(Clone)]
+// Prime field F_p. The backend decides how elements are stored and reduced;
+// all backends give the same results.
+pub struct PrimeField {
+    p: BigInt,
+    backend: Backend,
+}
+
+impl PrimeField {
+    // Uses the fastest backend supporting p
+    pub fn new(p: BigInt) -> Result<Self, Error> {
+        if p < BigInt::from(2) {
+            return Err(Error::InvalidModulus);
+        }
+        Ok(PrimeField::new_unchecked(p))
+    }
+
+    // Like new, but also accepts p < 2, for which every element is 0. Curve::new
+    // takes unvalidated parameters and leaves refusing them to Curve::validate.
+    pub(crate) fn new_unchecked(p: BigInt) -> Self {
+        let backend = Backend::new(&p, FieldBackend::best_for(&p))
+            .expect("best_for only picks supported backends");
+        PrimeField { p, backend }
+    }
+
+    pub fn with_backend(p: BigInt, backend: FieldBackend) -> Result<Self, Error> {
+        if p < BigInt::from(2) {
+            return Err(Error::InvalidModulus);
+        }
+        let backend = Backend::new(&p, backend).ok_or(Error::UnsupportedBackend)?;
+        Ok(PrimeField { p, backend })
+    }
+
+    pub fn modulus(&self) -> &BigInt {
+        &self.p
+    }
+
+    pub fn backend(&self) -> FieldBackend {
+        self.backend.kind()
+    }
+
+    pub fn element(&self, x: &BigInt) -> FieldElement<'_> {
+        if self.p <= BigInt::one() {
+            return self.zero();
+        }
+        let x = x.rem_euclid(&self.p);
+        let value = match &self.backend {
+            Backend::BigInt(f) => Repr::BigInt(f.encode(&x)),
+            Backend::Limbs3(f) => Repr::Limbs3(f.encode(&x)),
+            Backend::Limbs4(f) => Repr::Limbs4(f.encode(&x)),
+            Backend::Limbs5(f) => Repr::Limbs5(f.encode(&x)),
+            Backend::Limbs6(f) => Repr::Limbs6(f.encode(&x)),
+            Backend::Limbs8(f) => Repr::Limbs8(f.encode(&x)),
+            Backend::Limbs9(f) => Repr::Limbs9(f.encode(&x)),
+        };
+        FieldElement { field: self, value }
+    }
+
+    pub fn zero(&self) -> FieldElement<'_> {
+        FieldElement {
+            field: self,
+            value: dispatch!(&self.backend, repr, zero()),
+        }
+    }
+
+    pub fn one(&self) -> FieldElement<'_> {
+        self.element(&BigInt::one())
+    }
+}
+
+impl PartialEq for PrimeField {
+    fn eq(&self, other: &Self) -> bool {
+        self.p == other.p && self.backend() == other.backend()
+    }
+}
+
+impl Eq for PrimeField {}
+
+impl fmt::Debug for PrimeField {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "PrimeField {{ p: {}, backend: {:?} }}",
+            self.p,
+            self.backend()
+        )
+    }
+}
+
+#[derive(Clone)]
+// Element of a prime field, kept reduced in its field's representation
+pub struct FieldElement<'f> {
+    field: &'f PrimeField,
+    value: Repr,
+}
+
+impl<'f> FieldElement<'f> {
+    pub fn field(&self) -> &'f PrimeField {
+        self.field
+    }
+
+    // Canonical representative in [0, p)
+    pub fn to_bigint(&self) -> BigInt {
+        dispatch!(&self.field.backend, plain, decode(&self.value))
+    }
+
+    pub fn is_zero(&self) -> bool {
+        dispatch!(&self.field.backend, plain, is_zero(&self.value))
+    }
+
+    pub fn square(&self) -> FieldElement<'f> {
+        self * self
+    }
+
+    pub fn double(&self) -> FieldElement<'f> {
+        self + self
+    }
+
+    // self^exponent for a non-negative exponent, by square-and-multiply
+    pub fn pow(&self, exponent: &BigInt) -> FieldElement<'f> {
+        let mut r = self.field.one();
+        for i in (0..exponent.bits()).rev() {
+            r = r.square();
+            if exponent.bit(i) {
+                r = &r * self;
+            }
+        }
+        r
+    }
+
+    pub fn invert(&self) -> Result<FieldElement<'f>, Error> {
+        let inv = self
+            .to_bigint()
+            .modinv(&self.field.p)
+            .ok_or(Error::NonInvertible)?;
+        Ok(self.field.element(&inv))
+    }
+
+    pub fn sqrt(&self) -> Result<FieldElement<'f>, Error> {
+        Ok(self
+            .field
+            .element(&modsqrt(&self.to_bigint(), &self.field.p)?))
+    }
+
+    pub fn legendre(&self) -> i8 {
+        legendre(&self.to_bigint(), &self.field.p)
+    }
+
+    pub fn negate(&self) -> FieldElement<'f> {
+        &self.field.zero() - self
+    }
+
+    // Swaps a and b if choice is set, in constant time on the limb backends
+    pub fn conditional_swap(a: &mut FieldElement<'f>, b: &mut FieldElement<'f>, choice: bool) {
+        a.check_field(b);
+        a.value.conditional_swap(&mut b.value, choice);
+    }
+
+    // Invariant of every binary operation: both operands belong to the same field
+    // and backend. Points on the library's curves are always converted into one
+    // curve's field first, so only mixing elements by hand can break it; like the
+    // point and scalar operators, that is a programming error and panics.
+    fn check_field(&self, other: &FieldElement<'f>) {
+        if !std::ptr::eq(self.field, other.field) && self.field != other.field {
+            panic!("{}", Error::CurveMismatch);
+        }
+    }
+}
+
+impl<'f> Add<&FieldElement<'f>> for &FieldElement<'f> {
+    type Output = FieldElement<'f>;
+
+    fn add(self, other: &FieldElement<'f>) -> FieldElement<'f> {
+        self.check_field(other);
+        FieldElement {
+            field: self.field,
+            value: dispatch!(&self.field.backend, repr, add(&self.value, &other.value)),
+        }
+    }
+}
+
+impl<'f> Add<FieldElement<'f>> for FieldElement<'f> {
+    type Output = FieldElement<'f>;
+
+    fn add(self, other: FieldElement<'f>) -> FieldElement<'f> {
+        &self + &other
+    }
+}
+
+impl<'f> Sub<&FieldElement<'f>> for &FieldElement<'f> {
+    type Output = FieldElement<'f>;
+
+    fn sub(self, other: &FieldElement<'f>) -> FieldElement<'f> {
+        self.check_field(other);
+        FieldElement {
+            field: self.field,
+            value: dispatch!(&self.field.backend, repr, sub(&self.value, &other.value)),
+        }
+    }
+}
+
+impl<'f> Sub<FieldElement<'f>> for FieldElement<'f> {
+    type Output = FieldElement<'f>;
+
+    fn sub(self, other: FieldElement<'f>) -> FieldElement<'f> {
+        &self - &other
+    }
+}
+
+impl<'f> Mul<&FieldElement<'f>> for &FieldElement<'f> {
+    type Output = FieldElement<'f>;
+
+    fn mul(self, other: &FieldElement<'f>) -> FieldElement<'f> {
+        self.check_field(other);
+        FieldElement {
+            field: self.field,
+            value: dispatch!(&self.field.backend, repr, mul(&self.value, &other.value)),
+        }
+    }
+}
+
+impl<'f> Mul<FieldElement<'f>> for FieldElement<'f> {
+    type Output = FieldElement<'f>;
+
+    fn mul(self, other: FieldElement<'f>) -> FieldElement<'f> {
+        &self * &other
+    }
+}
+
+// Small constants are applied by doubling and adding, which every backend does cheaply
+impl<'f> Mul<u32> for &FieldElement<'f> {
+    type Output = FieldElement<'f>;
+
+    fn mul(self, k: u32) -> FieldElement<'f> {
+        let mut r = self.field.zero();
+        for i in (0..u32::BITS - k.leading_zeros()).rev() {
+            r = r.double();
+            if (k >> i) & 1 == 1 {
+                r = &r + self;
+            }
+        }
+        r
+    }
+}
+
+impl<'f> Neg for &FieldElement<'f> {
+    type Output = FieldElement<'f>;
+
+    fn neg(self) -> FieldElement<'f> {
+        self.negate()
+    }
+}
+
+impl<'f> Neg for FieldElement<'f> {
+    type Output = FieldElement<'f>;
+
+    fn neg(self) -> FieldElement<'f> {
+        self.negate()
+    }
+}
+
+impl<'f> PartialEq for FieldElement<'f> {
+    fn eq(&self, other: &Self) -> bool {
+        self.field == other.field && self.value == other.value
+    }
+}
+
+impl<'f> Eq for FieldElement<'f> {}
+
+impl<'f> fmt::Debug for FieldElement<'f> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "FieldElement {{ value: {} }}", self.to_bigint())
+    }
+}
+
+impl<'f> fmt::Display for FieldElement<'f> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_bigint())
+    }
+}
@@ -0,0 +1,213 @@
+use crate::curve::{Curve, SubGroup};
+use crate::error::Error;
+use crate::field_element::FieldElement;
+use crate::jacobian::JacobianPoint;
+use crate::point::{Point, DEFAULT_WNAF_WIDTH};
+use crate::scalar::Scalar;
+use num_bigint::{BigInt, Sign};
+use std::sync::{Arc, RwLock};
+
+// Bits of the scalar consumed per table row
+const WINDOW_BITS: u64 = 4;
+// Odd multiples 1, 3, ..., 15 held in each row
+const ROW_ENTRIES: usize = 1 << (WINDOW_BITS - 1);
+
+// Tables kept at most; the oldest is dropped to make room for a new one
+const CACHED_TABLES: usize = 32;
+
+// Domain parameters a table depends on, the same ones Curve::eq compares, so
+// equal curves share a table. Entries are stored as plain integers, which do not
+// depend on the field backend.
+#[derive(PartialEq, Eq)]
+struct TableKey {
+    a: BigInt,
+    b: BigInt,
+    field: SubGroup,
+}
+
+impl TableKey {
+    fn new(curve: &Curve) -> Self {
+        TableKey {
+            a: curve.a.clone(),
+            b: curve.b.clone(),
+            field: curve.field.clone(),
+        }
+    }
+}
+
+// Fixed-base table for a curve's generator G: row i holds j·2^(4i)·G for odd j in
+// [1, 16). A scalar recoded into one signed odd digit per row makes k·G the sum of
+// one entry per row, possibly negated, with no doubling and no zero digit.
+// Entries are stored as fixed-width words (x limbs, y limbs, infinity flag) so a
+// lookup can read a whole row and select with masks, independently of k.
+pub struct GeneratorTable {
+    limbs: usize,
+    rows: Vec<Vec<u64>>,
+}
+
+static GENERATOR_TABLES: RwLock<Vec<(TableKey, Arc<GeneratorTable>)>> = RwLock::new(Vec::new());
+
+fn push_words(words: &mut Vec<u64>, x: &BigInt, limbs: usize) {
+    let mut digits = x.to_u64_digits().1;
+    digits.resize(limbs, 0);
+    words.extend(digits);
+}
+
+fn from_words(words: &[u64]) -> BigInt {
+    let bytes: Vec<u8> = words.iter().flat_map(|word| word.to_le_bytes()).collect();
+    BigInt::from_bytes_le(Sign::Plus, &bytes)
+}
+
+// Digits d_i in {±1, ±3, ..., ±15} with k = Σ d_i·2^(4i), always `rows` of them.
+// k must be odd and below 2^(4·(rows - 1)).
+fn signed_odd_digits(k: &BigInt, rows: usize) -> Vec<i8> {
+    let radix = BigInt::from(1 << WINDOW_BITS);
+    let mut k = k.clone();
+    let mut digits = Vec::with_capacity(rows);
+    for _ in 1..rows {
+        // k is odd, so k mod 32 - 16 is odd and leaves k - d ≡ 16 (mod 32):
+        // the next k is odd again
+        let low = (&k & BigInt::from(2 * (1 << WINDOW_BITS) - 1))
+            .to_u64_digits()
+            .1;
+        let digit = low.first().copied().unwrap_or(0) as i8 - (1 << WINDOW_BITS);
+        k = (k - digit) / &radix;
+        digits.push(digit);
+    }
+    // What is left is 1, as k < 2^(4·(rows - 1))
+    digits.push(k.to_u64_digits().1[0] as i8);
+    digits
+}
+
+impl GeneratorTable {
+    fn build(curve: &Curve) -> Result<Self, Error> {
+        // The recoding needs odd scalars, which adding n only provides for odd n
+        if !curve.field.n.bit(0) {
+            return Err(Error::InvalidScalar);
+        }
+        let g = Point::new(curve, curve.field.g.0.clone(), curve.field.g.1.clone())?;
+        let limbs = curve.field.p.bits().div_ceil(64) as usize;
+        // Scalars are made odd by adding n, so they have up to one bit more than n
+        let row_count = (curve.field.n.bits() + 1).div_ceil(WINDOW_BITS) as usize + 1;
+        let mut base = JacobianPoint::from_affine(&g);
+        let mut multiples = Vec::with_capacity(row_count * ROW_ENTRIES);
+        for _ in 0..row_count {
+            let twice = base.double();
+            let mut multiple = base.clone();
+            for _ in 0..ROW_ENTRIES {
+                let next = multiple.add(&twice);
+                multiples.push(multiple);
+                multiple = next;
+            }
+            // multiple is now 17·base, so 16·base, the base of the next row, is one
+            // subtraction away
+            base = multiple.add(&base.negate());
+        }
+        let rows = Point::batch_from_jacobian(&multiples)?
+            .chunks(ROW_ENTRIES)
+            .map(|entries| {
+                let mut row = Vec::with_capacity(ROW_ENTRIES * (2 * limbs + 1));
+                for point in entries {
+                    push_words(&mut row, &point.x, limbs);
+                    push_words(&mut row, &point.y, limbs);
+                    row.push(u64::from(point.is_infinity()));
+                }
+                row
+            })
+            .collect();
+        Ok(GeneratorTable { limbs, rows })
+    }
+
+    // digit·2^(4i)·G for an odd digit, read by scanning the whole of row i and
+    // negated with a conditional swap
+    fn select<'c>(&self, curve: &'c Curve, row: usize, digit: i8) -> JacobianPoint<'c> {
+        let index = usize::from(digit.unsigned_abs() >> 1);
+        let width = 2 * self.limbs + 1;
+        let mut selected = vec![0u64; width];
+        for (j, entry) in self.rows[row].chunks(width).enumerate() {
+            let mask = u64::from(j == index).wrapping_neg();
+            for (s, e) in selected.iter_mut().zip(entry) {
+                *s |= e & mask;
+            }
+        }
+        let fp = curve.base_field();
+        let mut point = JacobianPoint::inf(curve);
+        point.x = fp.element(&from_words(&selected[..self.limbs]));
+        point.y = fp.element(&from_words(&selected[self.limbs..2 * self.limbs]));
+        point.z = fp.element(&BigInt::from(1 - selected[2 * self.limbs]));
+        let mut negated = point.y.negate();
+        FieldElement::conditional_swap(&mut point.y, &mut negated, digit < 0);
+        point
+    }
+
+    // k·G for k in [0, n). An even k is replaced by k + n, which has the same
+    // multiple of G, so that every k has the same number of nonzero digits; the
+    // sum starts from the first row's entry rather than the point at infinity.
+    fn mul<'c>(&self, curve: &'c Curve, k: &BigInt) -> JacobianPoint<'c> {
+        let n = &curve.field.n;
+        let odd = if k.bit(0) { k.clone() } else { k + n };
+        let digits = signed_odd_digits(&odd, self.rows.len());
+        let mut r = self.select(curve, 0, digits[0]);
+        for (row, digit) in digits.iter().enumerate().skip(1) {
+            r = r.add(&self.select(curve, row, *digit));
+        }
+        r
+    }
+}
+
+// Table for the generator of curve, built on first use and shared by all threads
+// and by all curves with the same domain parameters. Only the most recently built
+// CACHED_TABLES tables are kept. Fails with InvalidScalar for an even n.
+pub fn generator_table(curve: &Curve) -> Result<Arc<GeneratorTable>, Error> {
+    let key = TableKey::new(curve);
+    let find = |tables: &[(TableKey, Arc<GeneratorTable>)]| {
+        tables
+            .iter()
+            .find(|(k, _)| *k == key)
+            .map(|(_, table)| Arc::clone(table))
+    };
+    if let Some(table) = find(&GENERATOR_TABLES.read().unwrap_or_else(|e| e.into_inner())) {
+        return Ok(table);
+    }
+    // Built without holding the lock; if another thread wins the race its table is kept
+    let table = Arc::new(GeneratorTable::build(curve)?);
+    let mut tables = GENERATOR_TABLES.write().unwrap_or_else(|e| e.into_inner());
+    if let Some(existing) = find(&tables) {
+        return Ok(existing);
+    }
+    if tables.len() == CACHED_TABLES {
+        tables.remove(0);
+    }
+    tables.push((key, Arc::clone(&table)));
+    Ok(table)
+}
+
+// k·G for the curve's generator G through its precomputed table. Not constant
+// time, so only for public scalars; curves with an even n, which the table does
+// not support, fall back to wNAF.
+pub fn mul_generator<'c>(curve: &'c Curve, k: &Scalar<'c>) -> Result<Point<'c>, Error> {
+    if curve != k.curve() {
+        return Err(Error::CurveMismatch);
+    }
+    if !curve.field.n.bit(0) {
+        let g = Point::new(curve, curve.field.g.0.clone(), curve.field.g.1.clone())?;
+        return Point::mul_wnaf(&g, k.value().clone(), DEFAULT_WNAF_WIDTH);
+    }
+    generator_table(curve)?.mul(curve, k.value()).to_affine()
+}
+
+// k·G for many k at once, sharing one field inversion for the conversion to affine
+pub fn mul_generator_batch<'c>(
+    curve: &'c Curve,
+    ks: &[Scalar<'c>],
+) -> Result<Vec<Point<'c>>, Error> {
+    if ks.iter().any(|k| curve != k.curve()) {
+        return Err(Error::CurveMismatch);
+    }
+    if !curve.field.n.bit(0) {
+        return ks.iter().map(|k| mul_generator(curve, k)).collect();
+    }
+    let table = generator_table(curve)?;
+    let points: Vec<JacobianPoint<'c>> = ks.iter().map(|k| table.mul(curve, k.value())).collect();
+    Point::batch_from_jacobian(&points)
+}
@@ -0,0 +1,256 @@
+use crate::curve::Curve;
+use crate::error::Error;
+use crate::field_element::FieldElement;
+use crate::point::Point;
+#[cfg(feature = "test-utils")]
+use std::cell::Cell;
+use std::fmt;
+
+#[cfg(feature = "test-utils")]
+thread_local! {
+    static OPERATIONS: Cell<u64> = const { Cell::new(0) };
+}
+
+// Additions and doublings evaluated on this thread so far, leaving out those
+// answered by a shortcut for the point at infinity and additions handed over to
+// doubling. Lets the tests check that an algorithm does the same group operations
+// whatever the scalar.
+#[cfg(feature = "test-utils")]
+pub fn operation_count() -> u64 {
+    OPERATIONS.with(Cell::get)
+}
+
+#[inline]
+fn count_operation() {
+    #[cfg(feature = "test-utils")]
+    OPERATIONS.with(|count| count.set(count.get() + 1));
+}
+
+#[derive(Clone)]
+// Point in Jacobian projective coordinates (X : Y : Z) standing for the affine
+// point (X / Z^2, Y / Z^3), with Z = 0 for the point at infinity.
+// Addition and doubling need no field inversion; only to_affine inverts Z.
+pub struct JacobianPoint<'c> {
+    curve: &'c Curve,
+    pub x: FieldElement<'c>,
+    pub y: FieldElement<'c>,
+    pub z: FieldElement<'c>,
+}
+
+impl<'c> JacobianPoint<'c> {
+    pub fn inf(curve: &'c Curve) -> Self {
+        let fp = curve.base_field();
+        JacobianPoint {
+            curve,
+            x: fp.one(),
+            y: fp.one(),
+            z: fp.zero(),
+        }
+    }
+
+    pub fn from_affine(point: &Point<'c>) -> Self {
+        Self::from_affine_on(point.curve(), point)
+    }
+
+    // point in the base field of curve, which may use another backend than the
+    // curve point was made on
+    pub(crate) fn from_affine_on(curve: &'c Curve, point: &Point<'c>) -> Self {
+        if point.is_infinity() {
+            return Self::inf(curve);
+        }
+        let fp = curve.base_field();
+        JacobianPoint {
+            curve,
+            x: fp.element(&point.x),
+            y: fp.element(&point.y),
+            z: fp.one(),
+        }
+    }
+
+    pub fn to_affine(&self) -> Result<Point<'c>, Error> {
+        if self.is_infinity() {
+            return Ok(Point::inf(self.curve));
+        }
+        let z_inv = self.z.invert()?;
+        let z_inv2 = z_inv.square();
+        let x = &self.x * &z_inv2;
+        let y = &(&self.y * &z_inv2) * &z_inv;
+        Point::new(self.curve, x.to_bigint(), y.to_bigint())
+    }
+
+    pub fn curve(&self) -> &'c Curve {
+        self.curve
+    }
+
+    pub fn is_infinity(&self) -> bool {
+        self.z.is_zero()
+    }
+
+    pub fn negate(&self) -> Self {
+        JacobianPoint {
+            curve: self.curve,
+            x: self.x.clone(),
+            y: self.y.negate(),
+            z: self.z.clone(),
+        }
+    }
+
+    // Swaps a and b if choice is set, touching both either way
+    pub fn conditional_swap(a: &mut Self, b: &mut Self, choice: bool) {
+        FieldElement::conditional_swap(&mut a.x, &mut b.x, choice);
+        FieldElement::conditional_swap(&mut a.y, &mut b.y, choice);
+        FieldElement::conditional_swap(&mut a.z, &mut b.z, choice);
+    }
+
+    // dbl-2007-bl, or dbl-2001-b when a = -3 (mod p)
+    // https://hyperelliptic.org/EFD/g1p/auto-shortw-jacobian.html
+    pub fn double(&self) -> Self {
+        if self.is_infinity() || self.y.is_zero() {
+            return Self::inf(self.curve);
+        }
+        count_operation();
+        let (x, y, z) = (&self.x, &self.y, &self.z);
+        let a = self.curve.base_field().element(&self.curve.a);
+
+        if a == -&(&self.curve.base_field().one() * 3) {
+            let delta = z.square();
+            let gamma = y.square();
+            let beta = x * &gamma;
+            let alpha = &(&(x - &delta) * &(x + &delta)) * 3;
+            let x3 = &alpha.square() - &(&beta * 8);
+            let z3 = &(&(y + z).square() - &gamma) - &delta;
+            let y3 = &(&alpha * &(&(&beta * 4) - &x3)) - &(&gamma.square() * 8);
+            return JacobianPoint {
+                curve: self.curve,
+                x: x3,
+                y: y3,
+                z: z3,
+            };
+        }
+
+        let xx = x.square();
+        let yy = y.square();
+        let yyyy = yy.square();
+        let zz = z.square();
+        let s = (&(&(x + &yy).square() - &xx) - &yyyy).double();
+        let m = &(&xx * 3) + &(&a * &zz.square());
+        let x3 = &m.square() - &s.double();
+        let y3 = &(&m * &(&s - &x3)) - &(&yyyy * 8);
+        let z3 = &(&(y + z).square() - &yy) - &zz;
+        JacobianPoint {
+            curve: self.curve,
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    // add-2007-bl
+    pub fn add(&self, other: &JacobianPoint<'c>) -> Self {
+        if self.is_infinity() {
+            return other.clone();
+        }
+        if other.is_infinity() {
+            return self.clone();
+        }
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        let u1 = &self.x * &z2z2;
+        let u2 = &other.x * &z1z1;
+        let s1 = &(&self.y * &other.z) * &z2z2;
+        let s2 = &(&other.y * &self.z) * &z1z1;
+        let h = &u2 - &u1;
+        let r = (&s2 - &s1).double();
+        // Equal points are handed over to doubling, which counts itself
+        if h.is_zero() && r.is_zero() {
+            return self.double();
+        }
+        count_operation();
+        if h.is_zero() {
+            return Self::inf(self.curve);
+        }
+        let i = h.double().square();
+        let j = &h * &i;
+        let v = &u1 * &i;
+        let x3 = &(&r.square() - &j) - &v.double();
+        let y3 = &(&r * &(&v - &x3)) - &(&s1 * &j).double();
+        let z3 = &(&(&(&self.z + &other.z).square() - &z1z1) - &z2z2) * &h;
+        JacobianPoint {
+            curve: self.curve,
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+
+    // madd-2007-bl: addition of an affine point (Z2 = 1)
+    pub fn add_mixed(&self, other: &Point<'c>) -> Self {
+        if other.is_infinity() {
+            return self.clone();
+        }
+        if self.is_infinity() {
+            return Self::from_affine(other);
+        }
+        let fp = self.curve.base_field();
+        let z1z1 = self.z.square();
+        let u2 = &fp.element(&other.x) * &z1z1;
+        let s2 = &(&fp.element(&other.y) * &self.z) * &z1z1;
+        let h = &u2 - &self.x;
+        let r = (&s2 - &self.y).double();
+        // Equal points are handed over to doubling, which counts itself
+        if h.is_zero() && r.is_zero() {
+            return self.double();
+        }
+        count_operation();
+        if h.is_zero() {
+            return Self::inf(self.curve);
+        }
+        let hh = h.square();
+        let i = &hh * 4;
+        let j = &h * &i;
+        let v = &self.x * &i;
+        let x3 = &(&r.square() - &j) - &v.double();
+        let y3 = &(&r * &(&v - &x3)) - &(&self.y * &j).double();
+        let z3 = &(&(&self.z + &h).square() - &z1z1) - &hh;
+        JacobianPoint {
+            curve: self.curve,
+            x: x3,
+            y: y3,
+            z: z3,
+        }
+    }
+}
+
+impl<'c> From<&Point<'c>> for JacobianPoint<'c> {
+    fn from(point: &Point<'c>) -> Self {
+        JacobianPoint::from_affine(point)
+    }
+}
+
+impl<'c> PartialEq for JacobianPoint<'c> {
+    // Compares the underlying affine points: (X1 Z2^2, Y1 Z2^3) == (X2 Z1^2, Y2 Z1^3)
+    fn eq(&self, other: &Self) -> bool {
+        if self.curve != other.curve {
+            return false;
+        }
+        if self.is_infinity() || other.is_infinity() {
+            return self.is_infinity() && other.is_infinity();
+        }
+        let z1z1 = self.z.square();
+        let z2z2 = other.z.square();
+        &self.x * &z2z2 == &other.x * &z1z1
+            && &(&self.y * &other.z) * &z2z2 == &(&other.y * &self.z) * &z1z1
+    }
+}
+
+impl<'c> Eq for JacobianPoint<'c> {}
+
+impl<'c> fmt::Debug for JacobianPoint<'c> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(
+            f,
+            "JacobianPoint {{ x: {}, y: {}, z: {} }}",
+            self.x, self.y, self.z
+        )
+    }
+}
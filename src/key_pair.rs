@@ -1,11 +1,20 @@
 use crate::curve::Curve;
-use crate::point::Point;
+use crate::ecdh::SharedSecret;
+use crate::elgamal::{decode_message, encode_message, Ciphertext};
+use crate::error::Error;
+use crate::point::{montgomery_ladder, Point, DEFAULT_WNAF_WIDTH};
+use crate::rfc6979::NonceGenerator;
+use crate::scalar::Scalar;
+use crate::signature::Signature;
+use crate::utils::int_to_bytes;
+use hmac::digest::core_api::BlockSizeUser;
 use num_bigint::BigInt;
-use num_traits::Zero;
+use num_traits::One;
+use sha1::{Digest, Sha1};
 
 pub struct Keypair<'c> {
     pub curve: &'c Curve,
-    private_key: BigInt,
+    private_key: Scalar<'c>,
     pub public_key: Point<'c>,
     pub signs: bool,
     pub encrypts: bool,
@@ -16,30 +25,194 @@ impl<'c> Keypair<'c> {
         curve: &'c Curve,
         private_key: Option<&BigInt>,
         public_key: Option<&Point<'c>>,
-    ) -> Option<Self> {
+    ) -> Result<Self, Error> {
         if private_key.is_none() && public_key.is_none() {
-            eprintln!("Error: No private key or public key provided");
-            return None;
-        }
-        let g = Point::new(curve, curve.field.g.0.clone(), curve.field.g.1.clone())
-            .expect("Error creating curve point for g");
-        let signs = !private_key.is_none();
-        let private_key = if private_key.is_none() {
-            BigInt::zero()
-        } else {
-            private_key.unwrap().clone()
+            return Err(Error::NoKeyMaterial);
+        }
+        let signs = private_key.is_some();
+        let private_key = match private_key {
+            Some(private_key) => Self::private_scalar(curve, private_key)?,
+            None => Scalar::zero(curve),
         };
-        let public_key = if public_key.is_none() {
-            Point::mul_double_and_add(&g, private_key.clone()).unwrap()
-        } else {
-            public_key.unwrap().clone()
+        let public_key = match public_key {
+            Some(public_key) if curve != public_key.curve() => return Err(Error::CurveMismatch),
+            Some(public_key) => public_key.clone(),
+            None => Self::mul_secret(&Self::generator(curve)?, &private_key)?,
         };
-        Some(Keypair {
+        Ok(Keypair {
             curve,
             private_key,
             public_key,
             signs,
-            encrypts: false,
+            encrypts: signs,
         })
     }
+
+    // Keypairs for many private keys, deriving all public keys with a single field
+    // inversion
+    pub fn new_batch(curve: &'c Curve, private_keys: &[BigInt]) -> Result<Vec<Self>, Error> {
+        let private_keys = private_keys
+            .iter()
+            .map(|private_key| Self::private_scalar(curve, private_key))
+            .collect::<Result<Vec<_>, _>>()?;
+        let g = Self::generator(curve)?;
+        let public_keys: Vec<_> = private_keys
+            .iter()
+            .map(|private_key| montgomery_ladder(&g, private_key.value()))
+            .collect();
+        let public_keys = Point::batch_from_jacobian(&public_keys)?;
+        Ok(private_keys
+            .into_iter()
+            .zip(public_keys)
+            .map(|(private_key, public_key)| Keypair {
+                curve,
+                private_key,
+                public_key,
+                signs: true,
+                encrypts: true,
+            })
+            .collect())
+    }
+
+    // SEC 1 (section 3.2.1) private keys are integers in [1, n - 1]; anything else
+    // is refused rather than reduced, since d ≡ 0 would give the point at infinity
+    fn private_scalar(curve: &'c Curve, d: &BigInt) -> Result<Scalar<'c>, Error> {
+        if d < &BigInt::one() || d >= &curve.field.n {
+            return Err(Error::InvalidScalar);
+        }
+        Ok(Scalar::new(curve, d.clone()))
+    }
+
+    fn generator(curve: &'c Curve) -> Result<Point<'c>, Error> {
+        Point::new(curve, curve.field.g.0.clone(), curve.field.g.1.clone())
+    }
+
+    // Multiplication by the private key or a nonce, through the ladder; the faster
+    // generator tables in fixed_base are for public scalars only
+    fn mul_secret(point: &Point<'c>, k: &Scalar<'c>) -> Result<Point<'c>, Error> {
+        if point.curve() != k.curve() {
+            return Err(Error::CurveMismatch);
+        }
+        Point::mul_montgomery(point, k.value().clone())
+    }
+
+    // Hashes msg with D and truncates the digest to the bit length of n
+    fn hash_message<D: Digest>(&self, msg: &[u8]) -> (Vec<u8>, Scalar<'c>) {
+        let digest = D::digest(msg).to_vec();
+        let e = Scalar::from_digest(self.curve, &digest);
+        (digest, e)
+    }
+
+    // ECDSA signing with SHA-1 and deterministic nonces
+    pub fn sign(&self, msg: &[u8]) -> Result<Signature, Error> {
+        self.sign_with::<Sha1>(msg)
+    }
+
+    // ECDSA verification with SHA-1
+    pub fn verify(&self, msg: &[u8], signature: &Signature) -> bool {
+        self.verify_with::<Sha1>(msg, signature)
+    }
+
+    // ECDSA signing (SEC 1, section 4.1.3) with nonces derived as in RFC 6979
+    pub fn sign_with<D: Digest + BlockSizeUser>(&self, msg: &[u8]) -> Result<Signature, Error> {
+        if !self.signs {
+            return Err(Error::MissingPrivateKey);
+        }
+        let n = &self.curve.field.n;
+        let (digest, e) = self.hash_message::<D>(msg);
+        let g = Self::generator(self.curve)?;
+        for k in NonceGenerator::<D>::new(n, self.private_key.value(), &digest) {
+            let k = Scalar::new(self.curve, k);
+            let r = Scalar::new(self.curve, Self::mul_secret(&g, &k)?.x);
+            if r.is_zero() {
+                continue;
+            }
+            let s = k.invert()? * (&e + &(&r * &self.private_key));
+            if s.is_zero() {
+                continue;
+            }
+            return Ok(Signature::new(r.value().clone(), s.value().clone()));
+        }
+        Err(Error::NonceExhausted)
+    }
+
+    // ECDSA verification (SEC 1, section 4.1.4)
+    pub fn verify_with<D: Digest>(&self, msg: &[u8], signature: &Signature) -> bool {
+        let n = &self.curve.field.n;
+        let (r, s) = (&signature.r, &signature.s);
+        if r < &BigInt::one() || r >= n || s < &BigInt::one() || s >= n {
+            return false;
+        }
+        let (_, e) = self.hash_message::<D>(msg);
+        let w = match Scalar::new(self.curve, s.clone()).invert() {
+            Ok(w) => w,
+            Err(_) => return false,
+        };
+        let u1 = &e * &w;
+        let u2 = &Scalar::new(self.curve, r.clone()) * &w;
+        let x =
+            Self::generator(self.curve).and_then(|g| Point::mul2(&g, &u1, &self.public_key, &u2));
+        match x {
+            Ok(x) if !x.is_infinity() => &(x.x % n) == r,
+            _ => false,
+        }
+    }
+
+    // Elliptic Curve Diffie-Hellman primitive (SEC 1, section 3.3.1)
+    // Rejects peer keys outside the subgroup of order n before using the private key.
+    pub fn ecdh(&self, peer: &Point<'c>) -> Result<SharedSecret, Error> {
+        if !self.signs {
+            return Err(Error::MissingPrivateKey);
+        }
+        if self.curve != peer.curve() {
+            return Err(Error::CurveMismatch);
+        }
+        if peer.is_infinity() {
+            return Err(Error::PointAtInfinity);
+        }
+        if !self.curve.field.h.is_one() {
+            let check = Point::mul_wnaf(peer, self.curve.field.n.clone(), DEFAULT_WNAF_WIDTH)?;
+            if !check.is_infinity() {
+                return Err(Error::NotInSubgroup);
+            }
+        }
+        let z = Self::mul_secret(peer, &self.private_key)?;
+        if z.is_infinity() {
+            return Err(Error::PointAtInfinity);
+        }
+        let len = self.curve.field.p.bits().div_ceil(8) as usize;
+        Ok(SharedSecret::new(int_to_bytes(&z.x, len)))
+    }
+
+    // EC-ElGamal encryption of a curve point to this keypair's public key
+    pub fn encrypt(&self, m: &Point<'c>) -> Result<Ciphertext<'c>, Error> {
+        if self.curve != m.curve() {
+            return Err(Error::CurveMismatch);
+        }
+        let k = Scalar::random(self.curve);
+        let c1 = Self::mul_secret(&Self::generator(self.curve)?, &k)?;
+        let c2 = Point::add(m, &Self::mul_secret(&self.public_key, &k)?)?;
+        Ok(Ciphertext::new(c1, c2))
+    }
+
+    // EC-ElGamal decryption: M = c2 - d·c1
+    pub fn decrypt(&self, ciphertext: &Ciphertext<'c>) -> Result<Point<'c>, Error> {
+        if !self.encrypts {
+            return Err(Error::MissingPrivateKey);
+        }
+        if self.curve != ciphertext.c1.curve() || self.curve != ciphertext.c2.curve() {
+            return Err(Error::CurveMismatch);
+        }
+        let s = Self::mul_secret(&ciphertext.c1, &self.private_key)?;
+        Point::add(&ciphertext.c2, &s.negate())
+    }
+
+    // Encrypts a short byte string by first embedding it in a curve point
+    pub fn encrypt_bytes(&self, msg: &[u8]) -> Result<Ciphertext<'c>, Error> {
+        self.encrypt(&encode_message(self.curve, msg)?)
+    }
+
+    pub fn decrypt_bytes(&self, ciphertext: &Ciphertext<'c>) -> Result<Vec<u8>, Error> {
+        decode_message(&self.decrypt(ciphertext)?)
+    }
 }
@@ -1,5 +1,17 @@
 pub mod curve;
 pub mod curve_registry;
+pub mod ecdh;
+pub mod elgamal;
+pub mod error;
+pub mod field_element;
+pub mod fixed_base;
+pub mod jacobian;
 pub mod key_pair;
+pub mod limbs;
 pub mod point;
+pub mod rfc6979;
+pub mod scalar;
+pub mod signature;
 pub mod utils;
+
+pub use error::Error;
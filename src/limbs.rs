@@ -0,0 +1,419 @@
+use crate::field_element::{FieldArithmetic, FieldBackend};
+use num_bigint::{BigInt, Sign};
+use num_traits::{One, Zero};
+
+// Widest supported modulus: 9 limbs cover P-521
+pub const MAX_LIMBS: usize = 9;
+
+// Marks a zero word in the Solinas tables below
+const Z: u8 = u8::MAX;
+
+// NIST fast reduction (FIPS 186-4, appendix D.2) of a product A = (A[2W-1], ..., A[0])
+// of 32-bit words: A ≡ Σ coefficient·term (mod p), where each term lists the word
+// indices of a W-word number, most significant word first.
+struct Solinas {
+    p: &'static str,
+    words: usize,
+    terms: &'static [(i64, &'static [u8])],
+}
+
+const SOLINAS_PRIMES: &[Solinas] = &[
+    // P-192: 2^192 - 2^64 - 1
+    Solinas {
+        p: "fffffffffffffffffffffffffffffffeffffffffffffffff",
+        words: 6,
+        terms: &[
+            (1, &[5, 4, 3, 2, 1, 0]),
+            (1, &[Z, Z, 7, 6, 7, 6]),
+            (1, &[9, 8, 9, 8, Z, Z]),
+            (1, &[11, 10, 11, 10, 11, 10]),
+        ],
+    },
+    // P-224: 2^224 - 2^96 + 1
+    Solinas {
+        p: "ffffffffffffffffffffffffffffffff000000000000000000000001",
+        words: 7,
+        terms: &[
+            (1, &[6, 5, 4, 3, 2, 1, 0]),
+            (1, &[10, 9, 8, 7, Z, Z, Z]),
+            (1, &[Z, 13, 12, 11, Z, Z, Z]),
+            (-1, &[13, 12, 11, 10, 9, 8, 7]),
+            (-1, &[Z, Z, Z, Z, 13, 12, 11]),
+        ],
+    },
+    // P-256: 2^256 - 2^224 + 2^192 + 2^96 - 1
+    Solinas {
+        p: "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+        words: 8,
+        terms: &[
+            (1, &[7, 6, 5, 4, 3, 2, 1, 0]),
+            (2, &[15, 14, 13, 12, 11, Z, Z, Z]),
+            (2, &[Z, 15, 14, 13, 12, Z, Z, Z]),
+            (1, &[15, 14, Z, Z, Z, 10, 9, 8]),
+            (1, &[8, 13, 15, 14, 13, 11, 10, 9]),
+            (-1, &[10, 8, Z, Z, Z, 13, 12, 11]),
+            (-1, &[11, 9, Z, Z, 15, 14, 13, 12]),
+            (-1, &[12, Z, 10, 9, 8, 15, 14, 13]),
+            (-1, &[13, Z, 11, 10, 9, Z, 15, 14]),
+        ],
+    },
+    // P-384: 2^384 - 2^128 - 2^96 + 2^32 - 1
+    Solinas {
+        p: concat!(
+            "fffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffe",
+            "ffffffff0000000000000000ffffffff"
+        ),
+        words: 12,
+        terms: &[
+            (1, &[11, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 0]),
+            (2, &[Z, Z, Z, Z, Z, 23, 22, 21, Z, Z, Z, Z]),
+            (1, &[23, 22, 21, 20, 19, 18, 17, 16, 15, 14, 13, 12]),
+            (1, &[20, 19, 18, 17, 16, 15, 14, 13, 12, 23, 22, 21]),
+            (1, &[19, 18, 17, 16, 15, 14, 13, 12, 20, Z, 23, Z]),
+            (1, &[Z, Z, Z, Z, 23, 22, 21, 20, Z, Z, Z, Z]),
+            (1, &[Z, Z, Z, Z, Z, Z, 23, 22, 21, Z, Z, 20]),
+            (-1, &[22, 21, 20, 19, 18, 17, 16, 15, 14, 13, 12, 23]),
+            (-1, &[Z, Z, Z, Z, Z, Z, Z, 23, 22, 21, 20, Z]),
+            (-1, &[Z, Z, Z, Z, Z, Z, Z, 23, 23, Z, Z, Z]),
+        ],
+    },
+];
+
+#[derive(Clone)]
+enum Reduction<const N: usize> {
+    // -p^-1 mod 2^64 and R^2 mod p for R = 2^(64·N)
+    Montgomery { p_inv: u64, r2: [u64; N] },
+    Solinas(&'static Solinas),
+    // p = 2^(64·N) - c
+    PseudoMersenne(u64),
+}
+
+#[derive(Clone)]
+// Arithmetic modulo p on N 64-bit limbs (least significant first) without heap
+// allocation. Elements are kept fully reduced, in Montgomery form for the
+// Montgomery reduction and as plain residues for the others.
+pub struct LimbField<const N: usize> {
+    p: [u64; N],
+    reduction: Reduction<N>,
+}
+
+// a + b + carry, returning the low word and the carry
+#[inline(always)]
+fn adc(a: u64, b: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+// a - b - borrow, returning the low word and the borrow
+#[inline(always)]
+fn sbb(a: u64, b: u64, borrow: u64) -> (u64, u64) {
+    let t = (a as u128).wrapping_sub(b as u128 + borrow as u128);
+    (t as u64, (t >> 127) as u64)
+}
+
+// a + b·c + carry, returning the low word and the carry
+#[inline(always)]
+fn mac(a: u64, b: u64, c: u64, carry: u64) -> (u64, u64) {
+    let t = a as u128 + b as u128 * c as u128 + carry as u128;
+    (t as u64, (t >> 64) as u64)
+}
+
+fn to_limbs<const N: usize>(x: &BigInt) -> Option<[u64; N]> {
+    let (sign, digits) = x.to_u64_digits();
+    if sign == Sign::Minus || digits.len() > N {
+        return None;
+    }
+    let mut limbs = [0; N];
+    limbs[..digits.len()].copy_from_slice(&digits);
+    Some(limbs)
+}
+
+fn from_limbs(limbs: &[u64]) -> BigInt {
+    let bytes: Vec<u8> = limbs.iter().flat_map(|limb| limb.to_le_bytes()).collect();
+    BigInt::from_bytes_le(Sign::Plus, &bytes)
+}
+
+// a >= b for numbers of equal length
+fn geq(a: &[u64], b: &[u64]) -> bool {
+    for (x, y) in a.iter().zip(b).rev() {
+        if x != y {
+            return x > y;
+        }
+    }
+    true
+}
+
+// a -= b in place, returning the borrow
+fn sub_in_place(a: &mut [u64], b: &[u64]) -> u64 {
+    let mut borrow = 0;
+    for (x, y) in a.iter_mut().zip(b) {
+        (*x, borrow) = sbb(*x, *y, borrow);
+    }
+    borrow
+}
+
+// a += b in place, returning the carry
+fn add_in_place(a: &mut [u64], b: &[u64]) -> u64 {
+    let mut carry = 0;
+    for (x, y) in a.iter_mut().zip(b) {
+        (*x, carry) = adc(*x, *y, carry);
+    }
+    carry
+}
+
+// Schoolbook product of a and b into the first 2·N limbs of out
+fn mul_wide(a: &[u64], b: &[u64], out: &mut [u64]) {
+    let n = a.len();
+    out[..2 * n].fill(0);
+    for i in 0..n {
+        let mut carry = 0;
+        for j in 0..n {
+            (out[i + j], carry) = mac(out[i + j], a[i], b[j], carry);
+        }
+        out[i + n] = carry;
+    }
+}
+
+impl<const N: usize> LimbField<N> {
+    // Any odd modulus below 2^(64·N)
+    pub fn montgomery(p: &BigInt) -> Option<Self> {
+        let limbs = to_limbs::<N>(p)?;
+        if !p.bit(0) || p.is_one() {
+            return None;
+        }
+        // Newton iteration for p^-1 mod 2^64, doubling the correct bits each step
+        let mut inv: u64 = 1;
+        for _ in 0..6 {
+            inv = inv.wrapping_mul(2u64.wrapping_sub(limbs[0].wrapping_mul(inv)));
+        }
+        let r2 = to_limbs::<N>(&((BigInt::one() << (128 * N)) % p))?;
+        Some(LimbField {
+            p: limbs,
+            reduction: Reduction::Montgomery {
+                p_inv: inv.wrapping_neg(),
+                r2,
+            },
+        })
+    }
+
+    // One of the NIST primes P-192, P-224, P-256 or P-384
+    pub fn solinas(p: &BigInt) -> Option<Self> {
+        let solinas = SOLINAS_PRIMES.iter().find(|solinas| {
+            BigInt::parse_bytes(solinas.p.as_bytes(), 16).as_ref() == Some(p)
+                && solinas.words.div_ceil(2) == N
+        })?;
+        Some(LimbField {
+            p: to_limbs::<N>(p)?,
+            reduction: Reduction::Solinas(solinas),
+        })
+    }
+
+    // p = 2^(64·N) - c for some c < 2^64, such as secp256k1's 2^256 - 2^32 - 977
+    pub fn pseudo_mersenne(p: &BigInt) -> Option<Self> {
+        let c = (BigInt::one() << (64 * N)) - p;
+        if N < 2 || c <= BigInt::zero() || c.bits() > 64 || !p.bit(0) {
+            return None;
+        }
+        let c = c.to_u64_digits().1[0];
+        Some(LimbField {
+            p: to_limbs::<N>(p)?,
+            reduction: Reduction::PseudoMersenne(c),
+        })
+    }
+
+    pub fn kind(&self) -> FieldBackend {
+        match self.reduction {
+            Reduction::Montgomery { .. } => FieldBackend::Montgomery,
+            Reduction::Solinas(_) => FieldBackend::Solinas,
+            Reduction::PseudoMersenne(_) => FieldBackend::PseudoMersenne,
+        }
+    }
+
+    // Subtracts p once if value (with an extra top carry) is at least p
+    fn subtract_p_if_needed(&self, value: &mut [u64; N], carry: u64) {
+        if carry != 0 || geq(value, &self.p) {
+            sub_in_place(value, &self.p);
+        }
+    }
+
+    // Montgomery multiplication a·b·R^-1 mod p (CIOS)
+    fn mont_mul(&self, a: &[u64; N], b: &[u64; N], p_inv: u64) -> [u64; N] {
+        let mut t = [0u64; MAX_LIMBS + 2];
+        for b_i in b {
+            let mut carry = 0;
+            for j in 0..N {
+                (t[j], carry) = mac(t[j], a[j], *b_i, carry);
+            }
+            let (t_n, c) = adc(t[N], carry, 0);
+            t[N] = t_n;
+            t[N + 1] = c;
+
+            let m = t[0].wrapping_mul(p_inv);
+            let (_, mut carry) = mac(t[0], m, self.p[0], 0);
+            for j in 1..N {
+                (t[j - 1], carry) = mac(t[j], m, self.p[j], carry);
+            }
+            let (t_n, c) = adc(t[N], carry, 0);
+            t[N - 1] = t_n;
+            t[N] = t[N + 1] + c;
+        }
+        let mut result = [0u64; N];
+        result.copy_from_slice(&t[..N]);
+        self.subtract_p_if_needed(&mut result, t[N]);
+        result
+    }
+
+    fn solinas_reduce(&self, solinas: &Solinas, wide: &[u64]) -> [u64; N] {
+        let w = solinas.words;
+        let word = |i: u8| -> i64 {
+            if i == Z {
+                return 0;
+            }
+            let i = i as usize;
+            ((wide[i / 2] >> (32 * (i % 2))) & 0xffff_ffff) as i64
+        };
+
+        // Signed sum per 32-bit column, then carry propagation
+        let mut columns = [0i64; 12];
+        for (coefficient, term) in solinas.terms {
+            for (j, index) in term.iter().rev().enumerate() {
+                columns[j] += coefficient * word(*index);
+            }
+        }
+        let mut words = [0u32; 12];
+        let mut carry = 0i64;
+        for j in 0..w {
+            let v = columns[j] + carry;
+            words[j] = v as u32;
+            carry = v >> 32;
+        }
+
+        // Bring value = words + carry·2^(32·w) into [0, p)
+        let p_words: Vec<u32> = self
+            .p
+            .iter()
+            .flat_map(|limb| [*limb as u32, (*limb >> 32) as u32])
+            .take(w)
+            .collect();
+        let add_p = |words: &mut [u32; 12]| -> i64 {
+            let mut c = 0u64;
+            for j in 0..w {
+                let s = words[j] as u64 + p_words[j] as u64 + c;
+                words[j] = s as u32;
+                c = s >> 32;
+            }
+            c as i64
+        };
+        let sub_p = |words: &mut [u32; 12]| -> i64 {
+            let mut b = 0i64;
+            for j in 0..w {
+                let d = words[j] as i64 - p_words[j] as i64 - b;
+                words[j] = d as u32;
+                b = (d < 0) as i64;
+            }
+            b
+        };
+        let below_p = |words: &[u32; 12]| -> bool {
+            for j in (0..w).rev() {
+                if words[j] != p_words[j] {
+                    return words[j] < p_words[j];
+                }
+            }
+            false
+        };
+        while carry < 0 {
+            carry += add_p(&mut words);
+        }
+        while carry > 0 || !below_p(&words) {
+            carry -= sub_p(&mut words);
+        }
+
+        let mut result = [0u64; N];
+        for (j, limb) in result.iter_mut().enumerate() {
+            *limb = words[2 * j] as u64 | (words[2 * j + 1] as u64) << 32;
+        }
+        result
+    }
+
+    // Folds t = hi·2^(64·N) + lo to lo + hi·c until it fits, then subtracts p
+    fn pseudo_mersenne_reduce(&self, c: u64, wide: &[u64]) -> [u64; N] {
+        let mut result = [0u64; N];
+        let mut carry = 0;
+        for i in 0..N {
+            (result[i], carry) = mac(wide[i], wide[N + i], c, carry);
+        }
+        while carry != 0 {
+            let (low, high) = mac(0, carry, c, 0);
+            let mut addend = [0u64; N];
+            addend[0] = low;
+            addend[1] = high;
+            carry = add_in_place(&mut result, &addend);
+        }
+        while geq(&result, &self.p) {
+            sub_in_place(&mut result, &self.p);
+        }
+        result
+    }
+}
+
+impl<const N: usize> FieldArithmetic for LimbField<N> {
+    type Repr = [u64; N];
+
+    fn encode(&self, x: &BigInt) -> [u64; N] {
+        let limbs = to_limbs::<N>(x).expect("field elements are reduced below p");
+        match &self.reduction {
+            Reduction::Montgomery { p_inv, r2 } => self.mont_mul(&limbs, r2, *p_inv),
+            _ => limbs,
+        }
+    }
+
+    fn decode(&self, a: &[u64; N]) -> BigInt {
+        match &self.reduction {
+            Reduction::Montgomery { p_inv, .. } => {
+                let mut one = [0u64; N];
+                one[0] = 1;
+                from_limbs(&self.mont_mul(a, &one, *p_inv))
+            }
+            _ => from_limbs(a),
+        }
+    }
+
+    fn zero(&self) -> [u64; N] {
+        [0; N]
+    }
+
+    fn is_zero(&self, a: &[u64; N]) -> bool {
+        a.iter().all(|limb| *limb == 0)
+    }
+
+    fn add(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let mut sum = *a;
+        let carry = add_in_place(&mut sum, b);
+        self.subtract_p_if_needed(&mut sum, carry);
+        sum
+    }
+
+    fn sub(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        let mut difference = *a;
+        if sub_in_place(&mut difference, b) != 0 {
+            add_in_place(&mut difference, &self.p);
+        }
+        difference
+    }
+
+    fn mul(&self, a: &[u64; N], b: &[u64; N]) -> [u64; N] {
+        match &self.reduction {
+            Reduction::Montgomery { p_inv, .. } => self.mont_mul(a, b, *p_inv),
+            Reduction::Solinas(solinas) => {
+                let mut wide = [0u64; 2 * MAX_LIMBS];
+                mul_wide(a, b, &mut wide);
+                self.solinas_reduce(solinas, &wide)
+            }
+            Reduction::PseudoMersenne(c) => {
+                let mut wide = [0u64; 2 * MAX_LIMBS];
+                mul_wide(a, b, &mut wide);
+                self.pseudo_mersenne_reduce(*c, &wide)
+            }
+        }
+    }
+}
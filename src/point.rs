@@ -1,24 +1,47 @@
 use crate::curve::Curve;
-use crate::utils::modsqrt;
-use num_bigint::BigInt;
-use num_traits::{Euclid, One, ToPrimitive, Zero};
+use crate::error::Error;
+use crate::field_element::FieldElement;
+use crate::jacobian::JacobianPoint;
+use crate::scalar::Scalar;
+use crate::utils::{batch_invert, int_to_bytes, jsf, wnaf};
+use num_bigint::{BigInt, Sign};
+use num_traits::{Euclid, Signed, Zero};
 use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, RangeInclusive, Sub};
+
+// Window widths accepted by Point::mul_wnaf; width w precomputes 2^(w-2) points
+pub const WNAF_WIDTHS: RangeInclusive<u32> = 2..=10;
+pub const DEFAULT_WNAF_WIDTH: u32 = 5;
+// Number of terms from which Point::multiscalar_mul uses Pippenger's method
+pub const PIPPENGER_THRESHOLD: usize = 32;
 
 #[derive(Clone)]
+// Affine point on a curve. The point at infinity is flagged explicitly since
+// every pair of coordinates, (0, 0) included, can be a legitimate affine point.
 pub struct Point<'c> {
     curve: &'c Curve,
     pub x: BigInt,
     pub y: BigInt,
+    infinity: bool,
 }
 
 impl<'c> Point<'c> {
-    pub fn new(curve: &'c Curve, x: BigInt, y: BigInt) -> Option<Self> {
-        let on_curve = curve.on_curve(&x, &y);
-        if !on_curve {
-            eprintln!("Point ({}, {}) is not on curve \"{}\"", x, y, curve.name);
-            return None;
+    // Coordinates are stored reduced into [0, p)
+    pub fn new(curve: &'c Curve, x: BigInt, y: BigInt) -> Result<Self, Error> {
+        if !curve.on_curve(&x, &y) {
+            return Err(Error::NotOnCurve);
         }
-        Some(Point { x, y, curve })
+        let p = &curve.field.p;
+        Ok(Point {
+            x: x.rem_euclid(p),
+            y: y.rem_euclid(p),
+            curve,
+            infinity: false,
+        })
+    }
+
+    pub fn curve(&self) -> &'c Curve {
+        self.curve
     }
 
     #[inline]
@@ -27,104 +50,323 @@ impl<'c> Point<'c> {
             curve,
             x: BigInt::zero(),
             y: BigInt::zero(),
+            infinity: true,
         }
     }
 
-    pub fn add(this: &Point<'c>, other: &Point<'c>) -> Option<Point<'c>> {
+    #[inline]
+    pub fn is_infinity(&self) -> bool {
+        self.infinity
+    }
+
+    pub fn add(this: &Point<'c>, other: &Point<'c>) -> Result<Point<'c>, Error> {
         if this.curve != other.curve {
-            eprintln!("Cannot add points belonging to different curves");
-            return None;
+            return Err(Error::CurveMismatch);
         }
 
-        if this == &Point::inf(&this.curve) {
-            return Some(other.clone());
-        } else if other == &Point::inf(&other.curve) {
-            return Some(this.clone());
+        if this.is_infinity() {
+            return Ok(other.clone());
+        } else if other.is_infinity() {
+            return Ok(this.clone());
         }
 
-        if this.x == other.x && (&other.y + &this.y) % &other.curve.field.p == BigInt::zero() {
-            return Some(Point::inf(&this.curve));
+        let fp = this.curve.base_field();
+        let (x1, y1) = (fp.element(&this.x), fp.element(&this.y));
+        let (x2, y2) = (fp.element(&other.x), fp.element(&other.y));
+        if x1 == x2 && (&y1 + &y2).is_zero() {
+            return Ok(Point::inf(this.curve));
         }
 
-        let m: BigInt = if this.x == other.x {
-            ((3 * &this.x.pow(2) + &this.curve.a)
-                * (&BigInt::from(2) * &this.y)
-                    .modinv(&this.curve.field.p)
-                    .expect("Error computing modinv for 2y % p"))
-                % &this.curve.field.p
+        let m = if x1 == x2 {
+            &(&(&x1.square() * 3) + &fp.element(&this.curve.a)) * &y1.double().invert()?
         } else {
-            ((&other.y - &this.y)
-                * (&other.x - &this.x)
-                    .modinv(&this.curve.field.p)
-                    .expect("Error computing modinv for x1 - x2 % p"))
-                % &this.curve.field.p
+            &(&y2 - &y1) * &(&x2 - &x1).invert()?
         };
 
-        let x_r = (m.modpow(&BigInt::from(2), &this.curve.field.p) - &this.x - &other.x)
-            .rem_euclid(&this.curve.field.p);
-        let y_r = (m * (&this.x - &x_r) - &this.y).rem_euclid(&this.curve.field.p);
+        let x_r = &(&m.square() - &x1) - &x2;
+        let y_r = &(&m * &(&x1 - &x_r)) - &y1;
 
-        Point::new(&this.curve, x_r, y_r)
+        Point::new(this.curve, x_r.to_bigint(), y_r.to_bigint())
     }
 
-    pub fn mul_double_and_add(p: &Point<'c>, mut n: BigInt) -> Option<Point<'c>> {
-        let mut q = p.clone();
-        let mut r = Point::inf(p.curve);
-        while n > BigInt::zero() {
-            if &n % BigInt::from(2) == BigInt::one() {
-                r = Point::add(&r, &q).expect("Error adding points!");
+    // Sum of points on curve, accumulated in Jacobian coordinates with a single
+    // conversion back to affine; the point at infinity when there are none
+    pub fn sum<'a>(
+        curve: &'c Curve,
+        points: impl IntoIterator<Item = &'a Point<'c>>,
+    ) -> Result<Point<'c>, Error>
+    where
+        'c: 'a,
+    {
+        let mut acc = JacobianPoint::inf(curve);
+        for point in points {
+            if curve != point.curve {
+                return Err(Error::CurveMismatch);
             }
-            q = Point::add(&q, &q).expect("Error doubling point q!");
-            n /= 2;
+            acc = acc.add_mixed(point);
         }
-        Some(r)
+        acc.to_affine()
     }
 
-    // n: number of binary bits in k
-    pub fn mul_montgomery(p: &Point<'c>, k: BigInt, n: usize) -> Option<Point<'c>> {
-        let k_bin = k.to_radix_le(2).1;
-        if k_bin[0] == 0 {
-            eprintln!("k's msb must be 1!");
-            return None;
+    // Left-to-right double-and-add in Jacobian coordinates, converting back
+    // to affine coordinates once at the end. A negative n multiplies -p by |n|.
+    pub fn mul_double_and_add(p: &Point<'c>, n: BigInt) -> Result<Point<'c>, Error> {
+        if n < BigInt::zero() {
+            return Point::mul_double_and_add(&p.negate(), -n);
         }
-        let mut r0 = p.clone();
-        let mut r1 = Self::add(p, p).expect("Error doubling P!");
-        for i in (0..n - 1).rev() {
-            if k_bin[i] == 0 {
-                r1 = Self::add(&r0, &r1).expect("Error adding R0 and R1!");
-                r0 = Self::add(&r0, &r0).expect("Error doubling R0!");
-            } else {
-                r0 = Self::add(&r0, &r1).expect("Error adding R0 and R1!");
-                r1 = Self::add(&r1, &r1).expect("Error doubling R1!");
+        let mut r = JacobianPoint::inf(p.curve);
+        for i in (0..n.bits()).rev() {
+            r = r.double();
+            if n.bit(i) {
+                r = r.add_mixed(p);
+            }
+        }
+        r.to_affine()
+    }
+
+    // Multiplication by a scalar reduced modulo n, for points of the prime-order subgroup.
+    // Uses wNAF, which is not constant time; secret scalars belong in mul_montgomery.
+    pub fn mul_scalar(p: &Point<'c>, k: &Scalar<'c>) -> Result<Point<'c>, Error> {
+        if p.curve != k.curve() {
+            return Err(Error::CurveMismatch);
+        }
+        if p.curve.endomorphism().is_some() {
+            return straus(p.curve, &glv_terms(p, k.value())).to_affine();
+        }
+        Point::mul_wnaf(p, k.value().clone(), DEFAULT_WNAF_WIDTH)
+    }
+
+    // Converts many Jacobian points to affine with one field inversion in total
+    pub fn batch_from_jacobian(points: &[JacobianPoint<'c>]) -> Result<Vec<Point<'c>>, Error> {
+        let finite: Vec<FieldElement<'c>> = points
+            .iter()
+            .filter(|point| !point.is_infinity())
+            .map(|point| point.z.clone())
+            .collect();
+        let mut z_inverses = batch_invert(&finite)?.into_iter();
+        points
+            .iter()
+            .map(|point| {
+                if point.is_infinity() {
+                    return Ok(Point::inf(point.curve()));
+                }
+                let z_inv = z_inverses.next().expect("one inverse per finite point");
+                let z_inv2 = z_inv.square();
+                let x = &point.x * &z_inv2;
+                let y = &(&point.y * &z_inv2) * &z_inv;
+                Point::new(point.curve(), x.to_bigint(), y.to_bigint())
+            })
+            .collect()
+    }
+
+    // Width-w NAF multiplication with a table of the odd multiples P, 3P, ...,
+    // (2^(w-1) - 1)P. Runs in time depending on k, so only for public scalars.
+    pub fn mul_wnaf(p: &Point<'c>, k: BigInt, w: u32) -> Result<Point<'c>, Error> {
+        if !WNAF_WIDTHS.contains(&w) {
+            return Err(Error::InvalidWindowWidth);
+        }
+        if k.is_negative() {
+            return Point::mul_wnaf(&p.negate(), -k, w);
+        }
+        let base = JacobianPoint::from_affine(p);
+        let twice = base.double();
+        let mut odd_multiples = vec![base];
+        for i in 1..1 << (w - 2) {
+            odd_multiples.push(odd_multiples[i - 1].add(&twice));
+        }
+        let mut r = JacobianPoint::inf(p.curve);
+        for digit in wnaf(&k, w).into_iter().rev() {
+            r = r.double();
+            let multiple = &odd_multiples[(digit.unsigned_abs() / 2) as usize];
+            if digit > 0 {
+                r = r.add(multiple);
+            } else if digit < 0 {
+                r = r.add(&multiple.negate());
             }
         }
-        Some(r0)
+        r.to_affine()
+    }
+
+    // a·P + b·Q in one pass of doublings (Straus-Shamir), with a and b recoded in
+    // joint sparse form so only P, Q, P + Q and P - Q need to be precomputed
+    pub fn mul2(
+        p: &Point<'c>,
+        a: &Scalar<'c>,
+        q: &Point<'c>,
+        b: &Scalar<'c>,
+    ) -> Result<Point<'c>, Error> {
+        if p.curve != q.curve || p.curve != a.curve() || p.curve != b.curve() {
+            return Err(Error::CurveMismatch);
+        }
+        if p.curve.endomorphism().is_some() {
+            let mut terms = glv_terms(p, a.value());
+            terms.extend(glv_terms(q, b.value()));
+            return straus(p.curve, &terms).to_affine();
+        }
+        let jp = JacobianPoint::from_affine(p);
+        let jq = JacobianPoint::from_affine_on(p.curve, q);
+        let sum = jp.add(&jq);
+        let difference = jp.add(&jq.negate());
+        let mut r = JacobianPoint::inf(p.curve);
+        for (u0, u1) in jsf(a.value(), b.value()).into_iter().rev() {
+            r = r.double();
+            let (point, negative) = match (u0, u1) {
+                (0, 0) => continue,
+                (_, 0) => (&jp, u0 < 0),
+                (0, _) => (&jq, u1 < 0),
+                _ if u0 == u1 => (&sum, u0 < 0),
+                // u0 = -u1, so u0·P + u1·Q = u0·(P - Q)
+                _ => (&difference, u0 < 0),
+            };
+            r = if negative {
+                r.add(&point.negate())
+            } else {
+                r.add(point)
+            };
+        }
+        r.to_affine()
+    }
+
+    // Σ k_i·P_i, by interleaved wNAF (Straus) for few terms and Pippenger's bucket
+    // method from PIPPENGER_THRESHOLD terms on
+    pub fn multiscalar_mul(terms: &[(Scalar<'c>, Point<'c>)]) -> Result<Point<'c>, Error> {
+        let curve = match terms.first() {
+            Some((_, point)) => point.curve,
+            None => return Err(Error::NoTerms),
+        };
+        if terms
+            .iter()
+            .any(|(k, point)| k.curve() != curve || point.curve != curve)
+        {
+            return Err(Error::CurveMismatch);
+        }
+        let r = if terms.len() < PIPPENGER_THRESHOLD {
+            let terms: Vec<(BigInt, Point<'c>)> = terms
+                .iter()
+                .flat_map(|(k, point)| glv_terms(point, k.value()))
+                .collect();
+            straus(curve, &terms)
+        } else {
+            pippenger(curve, terms)
+        };
+        r.to_affine()
+    }
+
+    // Montgomery ladder over L = max(bits of n, bits of k) bits. k is offset by 2^L
+    // so that the ladder starts from p and 2p rather than the point at infinity, and
+    // 2^L·p, doubled alongside, is subtracted at the end. Every k < 2^L thus costs
+    // the same L additions and L + L doublings, and none of them takes the infinity
+    // shortcut unless a partial multiple of p is the point at infinity, which for p
+    // of large prime order happens only for a negligible fraction of k.
+    // The BigInt operations on k and the BigInt field backend are not constant time.
+    pub fn mul_montgomery(p: &Point<'c>, k: BigInt) -> Result<Point<'c>, Error> {
+        montgomery_ladder(p, &k).to_affine()
+    }
+
+    pub fn double(&self) -> Point<'c> {
+        unwrap_op(JacobianPoint::from_affine(self).double().to_affine())
+    }
+
+    pub fn negate(&self) -> Point<'c> {
+        if self.is_infinity() {
+            return self.clone();
+        }
+        let p = &self.curve.field.p;
+        Point {
+            curve: self.curve,
+            x: self.x.clone(),
+            y: (p - &self.y).rem_euclid(p),
+            infinity: false,
+        }
+    }
+
+    // Maps (x, y) to (u^2·x, u^3·y) on target, the isomorphism between
+    // y^2 = x^3 + ax + b and y^2 = x^3 + u^4·a·x + u^6·b
+    pub fn isomorphism<'t>(&self, target: &'t Curve, u: &BigInt) -> Result<Point<'t>, Error> {
+        if self.is_infinity() {
+            return Ok(Point::inf(target));
+        }
+        let fp = target.base_field();
+        let u = fp.element(u);
+        let u2 = u.square();
+        let x = &fp.element(&self.x) * &u2;
+        let y = &(&fp.element(&self.y) * &u2) * &u;
+        Point::new(target, x.to_bigint(), y.to_bigint())
     }
 
-    pub fn compress(&self) -> (BigInt, u8) {
-        (
-            self.x.clone(),
-            (self.y.clone() % BigInt::from(2))
-                .to_u8()
-                .expect("Error checking the parity"),
-        )
+    // The point at infinity has no x-coordinate and cannot be compressed
+    pub fn compress(&self) -> Result<(BigInt, u8), Error> {
+        if self.is_infinity() {
+            return Err(Error::PointAtInfinity);
+        }
+        Ok((self.x.clone(), u8::from(self.y.bit(0))))
     }
 
-    pub fn decompress(curve: &'c Curve, x: BigInt, is_odd: u8) -> Point<'c> {
-        let sqrt = modsqrt(&(&x.pow(3) + &curve.a * &x + &curve.b), &curve.field.p)
-            .expect("Error calculating the modular s");
-        let y = if is_odd == 1 {
-            sqrt
+    // SEC 1 (section 2.3.3) octet string: 0x00 for the point at infinity, otherwise
+    // 0x02 or 0x03 (by the parity of y) followed by x, or 0x04 followed by x and y,
+    // with coordinates as fixed-length big-endian integers of the byte length of p
+    pub fn to_sec1_bytes(&self, compressed: bool) -> Vec<u8> {
+        if self.is_infinity() {
+            return vec![0x00];
+        }
+        let len = self.curve.field.p.bits().div_ceil(8) as usize;
+        let mut bytes = Vec::with_capacity(1 + 2 * len);
+        if compressed {
+            bytes.push(0x02 | u8::from(self.y.bit(0)));
+            bytes.extend(int_to_bytes(&self.x, len));
         } else {
-            &curve.field.p - sqrt
+            bytes.push(0x04);
+            bytes.extend(int_to_bytes(&self.x, len));
+            bytes.extend(int_to_bytes(&self.y, len));
+        }
+        bytes
+    }
+
+    // Parses any SEC 1 (section 2.3.4) encoding: infinity, compressed, uncompressed or
+    // hybrid (0x06 or 0x07 by the parity of y, followed by x and y). Rejects wrong
+    // lengths, coordinates not below p, inconsistent hybrid parity and points off the curve.
+    pub fn from_sec1_bytes(curve: &'c Curve, bytes: &[u8]) -> Result<Point<'c>, Error> {
+        let len = curve.field.p.bits().div_ceil(8) as usize;
+        let coordinate = |bytes: &[u8]| {
+            let value = BigInt::from_bytes_be(Sign::Plus, bytes);
+            if value >= curve.field.p {
+                return Err(Error::InvalidEncoding);
+            }
+            Ok(value)
         };
-        Point::new(curve, x, y).unwrap()
+        match bytes {
+            [0x00] => Ok(Point::inf(curve)),
+            [tag @ (0x02 | 0x03), x @ ..] if x.len() == len => {
+                lift_x(curve, &coordinate(x)?, *tag == 0x03)
+            }
+            [tag @ (0x04 | 0x06 | 0x07), xy @ ..] if xy.len() == 2 * len => {
+                let (x, y) = (coordinate(&xy[..len])?, coordinate(&xy[len..])?);
+                if *tag != 0x04 && y.bit(0) != (*tag == 0x07) {
+                    return Err(Error::InvalidEncoding);
+                }
+                Point::new(curve, x, y)
+            }
+            _ => Err(Error::InvalidEncoding),
+        }
+    }
+
+    // Inverse of compress: the point with x-coordinate x whose y has parity is_odd.
+    // Fails for x outside [0, p), for is_odd other than 0 or 1, and for x with no
+    // point on the curve.
+    pub fn decompress(curve: &'c Curve, x: BigInt, is_odd: u8) -> Result<Point<'c>, Error> {
+        if x.is_negative() || x >= curve.field.p || is_odd > 1 {
+            return Err(Error::InvalidEncoding);
+        }
+        lift_x(curve, &x, is_odd == 1)
     }
 }
 
 impl<'c> PartialEq for Point<'c> {
     fn eq(&self, other: &Self) -> bool {
-        self.x == other.x && self.y == other.y && self.curve == other.curve
+        if self.curve != other.curve || self.infinity != other.infinity {
+            return false;
+        }
+        self.infinity || (self.x == other.x && self.y == other.y)
     }
 }
 
@@ -132,12 +374,338 @@ impl<'c> Eq for Point<'c> {}
 
 impl<'c> fmt::Debug for Point<'c> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.infinity {
+            return write!(f, "Point {{ infinity }}");
+        }
         write!(f, "Point {{ x: {}, y: {} }}", self.x, self.y)
     }
 }
 
 impl<'c> fmt::Display for Point<'c> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.infinity {
+            return write!(f, "inf");
+        }
         write!(f, "{},{}", self.x, self.y)
     }
 }
+
+// The point with x-coordinate x and y of the given parity
+fn lift_x<'c>(curve: &'c Curve, x: &BigInt, odd: bool) -> Result<Point<'c>, Error> {
+    let fp = curve.base_field();
+    let y_squared = curve.y_squared(&fp.element(x));
+    let root = y_squared.sqrt()?;
+    if root.square() != y_squared {
+        return Err(Error::NotAResidue);
+    }
+    let y = if root.to_bigint().bit(0) == odd {
+        root
+    } else {
+        root.negate()
+    };
+    // y = 0 has no odd representative
+    if y.to_bigint().bit(0) != odd {
+        return Err(Error::InvalidEncoding);
+    }
+    Point::new(curve, x.clone(), y.to_bigint())
+}
+
+// Point::mul_montgomery without the conversion to affine coordinates, so that
+// several results can share one inversion
+pub(crate) fn montgomery_ladder<'c>(p: &Point<'c>, k: &BigInt) -> JacobianPoint<'c> {
+    if k.is_negative() {
+        return montgomery_ladder(&p.negate(), &-k);
+    }
+    if p.infinity {
+        return JacobianPoint::inf(p.curve);
+    }
+    let bits = p.curve.field.n.bits().max(k.bits());
+    let mut r0 = JacobianPoint::from_affine(p);
+    let mut r1 = r0.double();
+    let mut offset = r0.clone();
+    // Invariant r1 = r0 + p. Instead of branching on each bit, the pair is
+    // swapped whenever the bit differs from the previous one.
+    let mut swapped = false;
+    for i in (0..bits).rev() {
+        let bit = k.bit(i);
+        JacobianPoint::conditional_swap(&mut r0, &mut r1, swapped ^ bit);
+        swapped = bit;
+        r1 = r0.add(&r1);
+        r0 = r0.double();
+        offset = offset.double();
+    }
+    JacobianPoint::conditional_swap(&mut r0, &mut r1, swapped);
+    r0.add(&offset.negate())
+}
+
+// k·P as terms for straus: with an endomorphism k·P = k1·P + k2·φ(P) where k1 and
+// k2 have half the length of n, their signs moved onto the points; otherwise k·P itself
+fn glv_terms<'c>(p: &Point<'c>, k: &BigInt) -> Vec<(BigInt, Point<'c>)> {
+    let Some(endomorphism) = p.curve.endomorphism() else {
+        return vec![(k.clone(), p.clone())];
+    };
+    let (k1, k2) = endomorphism.decompose(k, &p.curve.field.n);
+    let image = if p.infinity {
+        p.clone()
+    } else {
+        Point {
+            curve: p.curve,
+            x: (&endomorphism.beta * &p.x).rem_euclid(&p.curve.field.p),
+            y: p.y.clone(),
+            infinity: false,
+        }
+    };
+    let signed = |k: BigInt, point: Point<'c>| {
+        if k.is_negative() {
+            (-k, point.negate())
+        } else {
+            (k, point)
+        }
+    };
+    vec![signed(k1, p.clone()), signed(k2, image)]
+}
+
+// Interleaved width-4 NAF over non-negative scalars: one shared chain of doublings,
+// with each term adding from its own table of odd multiples
+fn straus<'c>(curve: &'c Curve, terms: &[(BigInt, Point<'c>)]) -> JacobianPoint<'c> {
+    const WIDTH: u32 = 4;
+    let tables: Vec<Vec<JacobianPoint>> = terms
+        .iter()
+        .map(|(_, point)| {
+            let base = JacobianPoint::from_affine_on(curve, point);
+            let twice = base.double();
+            let mut odd_multiples = vec![base];
+            for i in 1..1 << (WIDTH - 2) {
+                odd_multiples.push(odd_multiples[i - 1].add(&twice));
+            }
+            odd_multiples
+        })
+        .collect();
+    let digits: Vec<Vec<i64>> = terms.iter().map(|(k, _)| wnaf(k, WIDTH)).collect();
+    let len = digits.iter().map(Vec::len).max().unwrap_or(0);
+    let mut r = JacobianPoint::inf(curve);
+    for i in (0..len).rev() {
+        r = r.double();
+        for (table, digits) in tables.iter().zip(&digits) {
+            let digit = digits.get(i).copied().unwrap_or(0);
+            let multiple = &table[(digit.unsigned_abs() / 2) as usize];
+            if digit > 0 {
+                r = r.add(multiple);
+            } else if digit < 0 {
+                r = r.add(&multiple.negate());
+            }
+        }
+    }
+    r
+}
+
+// Pippenger: for each c-bit window of the scalars, points are added into the bucket
+// of their digit, and Σ d·bucket_d is formed with two running sums
+fn pippenger<'c>(curve: &'c Curve, terms: &[(Scalar<'c>, Point<'c>)]) -> JacobianPoint<'c> {
+    let c = (usize::BITS - terms.len().leading_zeros())
+        .saturating_sub(2)
+        .max(2) as u64;
+    let bits = curve.field.n.bits();
+    let mut r = JacobianPoint::inf(curve);
+    for window in (0..bits.div_ceil(c)).rev() {
+        for _ in 0..c {
+            r = r.double();
+        }
+        let mut buckets = vec![JacobianPoint::inf(curve); (1 << c) - 1];
+        for (k, point) in terms {
+            let digit = (0..c).fold(0usize, |acc, i| {
+                acc | usize::from(k.value().bit(window * c + i)) << i
+            });
+            if digit > 0 {
+                buckets[digit - 1] = buckets[digit - 1].add_mixed(point);
+            }
+        }
+        let mut running = JacobianPoint::inf(curve);
+        let mut window_sum = JacobianPoint::inf(curve);
+        for bucket in buckets.iter().rev() {
+            running = running.add(bucket);
+            window_sum = window_sum.add(&running);
+        }
+        r = r.add(&window_sum);
+    }
+    r
+}
+
+// The operators below panic where Point::add and Point::mul_scalar would return
+// an error, i.e. when mixing points or scalars of different curves. That is a
+// programming error; call those functions instead where the curves may differ.
+fn unwrap_op(result: Result<Point, Error>) -> Point {
+    result.unwrap_or_else(|e| panic!("{}", e))
+}
+
+impl<'c> Add<&Point<'c>> for &Point<'c> {
+    type Output = Point<'c>;
+
+    fn add(self, other: &Point<'c>) -> Point<'c> {
+        unwrap_op(Point::add(self, other))
+    }
+}
+
+impl<'c> Add<Point<'c>> for &Point<'c> {
+    type Output = Point<'c>;
+
+    fn add(self, other: Point<'c>) -> Point<'c> {
+        self + &other
+    }
+}
+
+impl<'c> Add<&Point<'c>> for Point<'c> {
+    type Output = Point<'c>;
+
+    fn add(self, other: &Point<'c>) -> Point<'c> {
+        &self + other
+    }
+}
+
+impl<'c> Add<Point<'c>> for Point<'c> {
+    type Output = Point<'c>;
+
+    fn add(self, other: Point<'c>) -> Point<'c> {
+        &self + &other
+    }
+}
+
+impl<'c> AddAssign<&Point<'c>> for Point<'c> {
+    fn add_assign(&mut self, other: &Point<'c>) {
+        *self = &*self + other;
+    }
+}
+
+impl<'c> AddAssign<Point<'c>> for Point<'c> {
+    fn add_assign(&mut self, other: Point<'c>) {
+        *self = &*self + &other;
+    }
+}
+
+impl<'c> Neg for &Point<'c> {
+    type Output = Point<'c>;
+
+    fn neg(self) -> Point<'c> {
+        self.negate()
+    }
+}
+
+impl<'c> Neg for Point<'c> {
+    type Output = Point<'c>;
+
+    fn neg(self) -> Point<'c> {
+        self.negate()
+    }
+}
+
+impl<'c> Sub<&Point<'c>> for &Point<'c> {
+    type Output = Point<'c>;
+
+    fn sub(self, other: &Point<'c>) -> Point<'c> {
+        unwrap_op(Point::add(self, &other.negate()))
+    }
+}
+
+impl<'c> Sub<Point<'c>> for &Point<'c> {
+    type Output = Point<'c>;
+
+    fn sub(self, other: Point<'c>) -> Point<'c> {
+        self - &other
+    }
+}
+
+impl<'c> Sub<&Point<'c>> for Point<'c> {
+    type Output = Point<'c>;
+
+    fn sub(self, other: &Point<'c>) -> Point<'c> {
+        &self - other
+    }
+}
+
+impl<'c> Sub<Point<'c>> for Point<'c> {
+    type Output = Point<'c>;
+
+    fn sub(self, other: Point<'c>) -> Point<'c> {
+        &self - &other
+    }
+}
+
+impl<'c> Mul<&BigInt> for &Point<'c> {
+    type Output = Point<'c>;
+
+    fn mul(self, k: &BigInt) -> Point<'c> {
+        unwrap_op(Point::mul_double_and_add(self, k.clone()))
+    }
+}
+
+impl<'c> Mul<BigInt> for &Point<'c> {
+    type Output = Point<'c>;
+
+    fn mul(self, k: BigInt) -> Point<'c> {
+        unwrap_op(Point::mul_double_and_add(self, k))
+    }
+}
+
+impl<'c> Mul<&BigInt> for Point<'c> {
+    type Output = Point<'c>;
+
+    fn mul(self, k: &BigInt) -> Point<'c> {
+        &self * k
+    }
+}
+
+impl<'c> Mul<BigInt> for Point<'c> {
+    type Output = Point<'c>;
+
+    fn mul(self, k: BigInt) -> Point<'c> {
+        &self * k
+    }
+}
+
+impl<'c> Mul<&Point<'c>> for &BigInt {
+    type Output = Point<'c>;
+
+    fn mul(self, point: &Point<'c>) -> Point<'c> {
+        point * self
+    }
+}
+
+impl<'c> Mul<&Scalar<'c>> for &Point<'c> {
+    type Output = Point<'c>;
+
+    fn mul(self, k: &Scalar<'c>) -> Point<'c> {
+        unwrap_op(Point::mul_scalar(self, k))
+    }
+}
+
+impl<'c> Mul<Scalar<'c>> for &Point<'c> {
+    type Output = Point<'c>;
+
+    fn mul(self, k: Scalar<'c>) -> Point<'c> {
+        self * &k
+    }
+}
+
+impl<'c> Mul<&Scalar<'c>> for Point<'c> {
+    type Output = Point<'c>;
+
+    fn mul(self, k: &Scalar<'c>) -> Point<'c> {
+        &self * k
+    }
+}
+
+impl<'c> Mul<Scalar<'c>> for Point<'c> {
+    type Output = Point<'c>;
+
+    fn mul(self, k: Scalar<'c>) -> Point<'c> {
+        &self * &k
+    }
+}
+
+impl<'c> Mul<&Point<'c>> for &Scalar<'c> {
+    type Output = Point<'c>;
+
+    fn mul(self, point: &Point<'c>) -> Point<'c> {
+        point * self
+    }
+}
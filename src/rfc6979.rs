@@ -0,0 +1,78 @@
+use crate::utils::{bits_to_int, hmac, int_to_bytes};
+use hmac::digest::core_api::BlockSizeUser;
+use hmac::digest::Digest;
+use num_bigint::BigInt;
+use num_traits::Zero;
+use std::marker::PhantomData;
+
+// Deterministic ECDSA nonce generator (RFC 6979, section 3.2)
+// Yields the candidate nonces k in [1, n - 1] in order, so a signer
+// can simply take the next one whenever r or s turns out to be zero.
+pub struct NonceGenerator<D: Digest + BlockSizeUser> {
+    n: BigInt,
+    qlen: u64,
+    k: Vec<u8>,
+    v: Vec<u8>,
+    started: bool,
+    digest: PhantomData<D>,
+}
+
+impl<D: Digest + BlockSizeUser> NonceGenerator<D> {
+    // n: order of the subgroup, x: private key, h1: hash of the message
+    pub fn new(n: &BigInt, x: &BigInt, h1: &[u8]) -> Self {
+        let qlen = n.bits();
+        let rlen = qlen.div_ceil(8) as usize;
+        let hlen = <D as Digest>::output_size();
+
+        let x = int_to_bytes(x, rlen);
+        let h = int_to_bytes(&(bits_to_int(h1, qlen) % n), rlen);
+
+        let mut v = vec![0x01; hlen];
+        let mut k = vec![0x00; hlen];
+        for marker in [0x00, 0x01] {
+            k = hmac::<D>(&k, &[&v, &[marker], &x, &h]);
+            v = hmac::<D>(&k, &[&v]);
+        }
+
+        NonceGenerator {
+            n: n.clone(),
+            qlen,
+            k,
+            v,
+            started: false,
+            digest: PhantomData,
+        }
+    }
+}
+
+impl<D: Digest + BlockSizeUser> Iterator for NonceGenerator<D> {
+    type Item = BigInt;
+
+    fn next(&mut self) -> Option<BigInt> {
+        if self.started {
+            self.k = hmac::<D>(&self.k, &[&self.v, &[0x00]]);
+            self.v = hmac::<D>(&self.k, &[&self.v]);
+        }
+        self.started = true;
+        loop {
+            let mut t = Vec::new();
+            while (t.len() as u64) * 8 < self.qlen {
+                self.v = hmac::<D>(&self.k, &[&self.v]);
+                t.extend_from_slice(&self.v);
+            }
+            let k = bits_to_int(&t, self.qlen);
+            if !k.is_zero() && k < self.n {
+                return Some(k);
+            }
+            self.k = hmac::<D>(&self.k, &[&self.v, &[0x00]]);
+            self.v = hmac::<D>(&self.k, &[&self.v]);
+        }
+    }
+}
+
+// Returns the first RFC 6979 nonce for private key x and message hash h1
+pub fn generate_k<D: Digest + BlockSizeUser>(n: &BigInt, x: &BigInt, h1: &[u8]) -> BigInt {
+    NonceGenerator::<D>::new(n, x, h1)
+        .next()
+        .expect("Nonce generator never runs dry")
+}
@@ -0,0 +1,178 @@
+use crate::curve::Curve;
+use crate::error::Error;
+use crate::utils::{bits_to_int, int_to_bytes};
+use hmac::digest::Digest;
+use num_bigint::{BigInt, RandBigInt, Sign};
+use num_traits::{Euclid, One, Zero};
+use std::fmt;
+use std::ops::{Add, Mul, Neg, Sub};
+
+#[derive(Clone)]
+// Integer modulo the order n of a curve's subgroup, always kept in [0, n)
+pub struct Scalar<'c> {
+    curve: &'c Curve,
+    value: BigInt,
+}
+
+impl<'c> Scalar<'c> {
+    pub fn new(curve: &'c Curve, value: BigInt) -> Self {
+        Scalar {
+            value: value.rem_euclid(&curve.field.n),
+            curve,
+        }
+    }
+
+    pub fn zero(curve: &'c Curve) -> Self {
+        Scalar::new(curve, BigInt::zero())
+    }
+
+    pub fn one(curve: &'c Curve) -> Self {
+        Scalar::new(curve, BigInt::one())
+    }
+
+    // Uniformly random non-zero scalar, e.g. a private key or an ephemeral nonce
+    pub fn random(curve: &'c Curve) -> Self {
+        let value = rand::thread_rng().gen_bigint_range(&BigInt::one(), &curve.field.n);
+        Scalar { curve, value }
+    }
+
+    // Big-endian bytes of any length, reduced modulo n
+    pub fn from_bytes_be(curve: &'c Curve, bytes: &[u8]) -> Self {
+        Scalar::new(curve, BigInt::from_bytes_be(Sign::Plus, bytes))
+    }
+
+    // Leftmost bits of a message digest, as many as n has, reduced modulo n
+    // (SEC 1, section 4.1.3, step 5)
+    pub fn from_digest(curve: &'c Curve, digest: &[u8]) -> Self {
+        Scalar::new(curve, bits_to_int(digest, curve.field.n.bits()))
+    }
+
+    pub fn hash<D: Digest>(curve: &'c Curve, msg: &[u8]) -> Self {
+        Scalar::from_digest(curve, &D::digest(msg))
+    }
+
+    pub fn curve(&self) -> &'c Curve {
+        self.curve
+    }
+
+    pub fn value(&self) -> &BigInt {
+        &self.value
+    }
+
+    pub fn is_zero(&self) -> bool {
+        self.value.is_zero()
+    }
+
+    // Big-endian encoding padded to the byte length of n
+    pub fn to_bytes_be(&self) -> Vec<u8> {
+        int_to_bytes(&self.value, self.curve.field.n.bits().div_ceil(8) as usize)
+    }
+
+    pub fn invert(&self) -> Result<Scalar<'c>, Error> {
+        let value = self
+            .value
+            .modinv(&self.curve.field.n)
+            .ok_or(Error::NonInvertible)?;
+        Ok(Scalar {
+            curve: self.curve,
+            value,
+        })
+    }
+
+    pub fn negate(&self) -> Scalar<'c> {
+        Scalar::new(self.curve, -&self.value)
+    }
+
+    // Applies op to both values after checking the scalars share a curve.
+    // Like the point operators, mixing curves is a programming error and panics.
+    fn combine(&self, other: &Scalar<'c>, op: impl Fn(&BigInt, &BigInt) -> BigInt) -> Scalar<'c> {
+        if self.curve != other.curve {
+            panic!("{}", Error::CurveMismatch);
+        }
+        Scalar::new(self.curve, op(&self.value, &other.value))
+    }
+}
+
+impl<'c> Add<&Scalar<'c>> for &Scalar<'c> {
+    type Output = Scalar<'c>;
+
+    fn add(self, other: &Scalar<'c>) -> Scalar<'c> {
+        self.combine(other, |a, b| a + b)
+    }
+}
+
+impl<'c> Add<Scalar<'c>> for Scalar<'c> {
+    type Output = Scalar<'c>;
+
+    fn add(self, other: Scalar<'c>) -> Scalar<'c> {
+        &self + &other
+    }
+}
+
+impl<'c> Sub<&Scalar<'c>> for &Scalar<'c> {
+    type Output = Scalar<'c>;
+
+    fn sub(self, other: &Scalar<'c>) -> Scalar<'c> {
+        self.combine(other, |a, b| a - b)
+    }
+}
+
+impl<'c> Sub<Scalar<'c>> for Scalar<'c> {
+    type Output = Scalar<'c>;
+
+    fn sub(self, other: Scalar<'c>) -> Scalar<'c> {
+        &self - &other
+    }
+}
+
+impl<'c> Mul<&Scalar<'c>> for &Scalar<'c> {
+    type Output = Scalar<'c>;
+
+    fn mul(self, other: &Scalar<'c>) -> Scalar<'c> {
+        self.combine(other, |a, b| a * b)
+    }
+}
+
+impl<'c> Mul<Scalar<'c>> for Scalar<'c> {
+    type Output = Scalar<'c>;
+
+    fn mul(self, other: Scalar<'c>) -> Scalar<'c> {
+        &self * &other
+    }
+}
+
+impl<'c> Neg for &Scalar<'c> {
+    type Output = Scalar<'c>;
+
+    fn neg(self) -> Scalar<'c> {
+        self.negate()
+    }
+}
+
+impl<'c> Neg for Scalar<'c> {
+    type Output = Scalar<'c>;
+
+    fn neg(self) -> Scalar<'c> {
+        self.negate()
+    }
+}
+
+impl<'c> PartialEq for Scalar<'c> {
+    fn eq(&self, other: &Self) -> bool {
+        self.curve == other.curve && self.value == other.value
+    }
+}
+
+impl<'c> Eq for Scalar<'c> {}
+
+impl<'c> fmt::Debug for Scalar<'c> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Scalar {{ value: {} }}", self.value)
+    }
+}
+
+impl<'c> fmt::Display for Scalar<'c> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
@@ -0,0 +1,27 @@
+use num_bigint::BigInt;
+use std::fmt;
+
+#[derive(Clone, PartialEq, Eq)]
+// ECDSA signature over a curve's subgroup of order n
+pub struct Signature {
+    pub r: BigInt,
+    pub s: BigInt,
+}
+
+impl Signature {
+    pub fn new(r: BigInt, s: BigInt) -> Self {
+        Signature { r, s }
+    }
+}
+
+impl fmt::Debug for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Signature {{ r: {}, s: {} }}", self.r, self.s)
+    }
+}
+
+impl fmt::Display for Signature {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({:x}, {:x})", self.r, self.s)
+    }
+}
@@ -1,9 +1,240 @@
-use num_bigint::BigInt;
-use num_traits::One;
+use crate::error::Error;
+use crate::field_element::FieldElement;
+use hmac::digest::core_api::BlockSizeUser;
+use hmac::digest::Digest;
+use hmac::{Mac, SimpleHmac};
+use num_bigint::{BigInt, RandBigInt, Sign};
+use num_traits::{Euclid, One, Zero};
 
-pub fn modsqrt(square: &BigInt, modulus: &BigInt) -> Option<BigInt> {
-    if modulus % BigInt::from(4) == BigInt::from(3) {
-        return Some(square.modpow(&((modulus + BigInt::one()) / BigInt::from(4)), modulus));
+// Miller-Rabin primality test with random bases, preceded by trial division by small primes
+pub fn is_probable_prime(n: &BigInt) -> bool {
+    const SMALL_PRIMES: [u32; 15] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47];
+    const ROUNDS: usize = 40;
+
+    if n < &BigInt::from(2) {
+        return false;
+    }
+    for prime in SMALL_PRIMES {
+        if n == &BigInt::from(prime) {
+            return true;
+        }
+        if (n % prime).is_zero() {
+            return false;
+        }
+    }
+
+    // n - 1 = d·2^s with d odd
+    let n_minus_one: BigInt = n - 1;
+    let s = n_minus_one
+        .trailing_zeros()
+        .expect("n - 1 is non-zero for n > 2");
+    let d = &n_minus_one >> s;
+
+    let mut rng = rand::thread_rng();
+    'witness: for _ in 0..ROUNDS {
+        let a = rng.gen_bigint_range(&BigInt::from(2), &n_minus_one);
+        let mut x = a.modpow(&d, n);
+        if x.is_one() || x == n_minus_one {
+            continue;
+        }
+        for _ in 1..s {
+            x = (&x * &x) % n;
+            if x == n_minus_one {
+                continue 'witness;
+            }
+        }
+        return false;
+    }
+    true
+}
+
+// Square root of square modulo an odd prime, or NotAResidue if square is a quadratic non-residue.
+// Uses the direct exponentiations for p ≡ 3 (mod 4) and Atkin's method for p ≡ 5 (mod 8),
+// falling back to Tonelli-Shanks for p ≡ 1 (mod 8).
+pub fn modsqrt(square: &BigInt, modulus: &BigInt) -> Result<BigInt, Error> {
+    let a = square.rem_euclid(modulus);
+    if a.is_zero() || modulus == &BigInt::from(2) {
+        return Ok(a);
+    }
+    if legendre(&a, modulus) != 1 {
+        return Err(Error::NotAResidue);
+    }
+
+    let root = if modulus % 4 == BigInt::from(3) {
+        a.modpow(&((modulus + 1) / 4), modulus)
+    } else if modulus % 8 == BigInt::from(5) {
+        atkin(&a, modulus)
+    } else {
+        tonelli_shanks(&a, modulus)
+    };
+    debug_assert_eq!(root.modpow(&BigInt::from(2), modulus), a);
+    Ok(root)
+}
+
+// Legendre symbol (a/p) via Euler's criterion: 1 for non-zero squares, -1 for
+// non-squares and 0 when p divides a
+pub fn legendre(a: &BigInt, p: &BigInt) -> i8 {
+    let ls = a.modpow(&((p - 1) / 2), p);
+    if ls.is_zero() {
+        0
+    } else if ls.is_one() {
+        1
+    } else {
+        -1
+    }
+}
+
+fn atkin(a: &BigInt, p: &BigInt) -> BigInt {
+    let two_a: BigInt = (a * 2) % p;
+    let gamma = two_a.modpow(&((p - 5) / 8), p);
+    let i: BigInt = (&two_a * &gamma * &gamma) % p;
+    let root: BigInt = a * &gamma * (i - 1);
+    root.rem_euclid(p)
+}
+
+fn tonelli_shanks(a: &BigInt, p: &BigInt) -> BigInt {
+    // p - 1 = q·2^s with q odd
+    let p_minus_one: BigInt = p - 1;
+    let s = p_minus_one
+        .trailing_zeros()
+        .expect("p - 1 is non-zero for an odd prime");
+    let q = &p_minus_one >> s;
+
+    let mut z = BigInt::from(2);
+    while legendre(&z, p) != -1 {
+        z += 1;
+    }
+
+    let mut m = s;
+    let mut c = z.modpow(&q, p);
+    let mut t = a.modpow(&q, p);
+    let mut r = a.modpow(&((&q + 1) / 2), p);
+    while !t.is_one() {
+        // Find the least i with t^(2^i) = 1
+        let mut i = 0;
+        let mut t2i = t.clone();
+        while !t2i.is_one() {
+            t2i = (&t2i * &t2i) % p;
+            i += 1;
+        }
+        let b = c.modpow(&(BigInt::one() << (m - i - 1)), p);
+        m = i;
+        c = (&b * &b) % p;
+        t = (t * &c) % p;
+        r = (r * b) % p;
+    }
+    r
+}
+
+// Interprets a byte string as a big-endian integer keeping only its leftmost qlen bits
+// (bits2int in RFC 6979, and the message truncation step of ECDSA)
+pub fn bits_to_int(bytes: &[u8], qlen: u64) -> BigInt {
+    let x = BigInt::from_bytes_be(Sign::Plus, bytes);
+    let blen = bytes.len() as u64 * 8;
+    if blen > qlen {
+        x >> (blen - qlen)
+    } else {
+        x
+    }
+}
+
+// Big-endian encoding of a non-negative x padded to exactly len bytes
+// (int2octets in RFC 6979, FieldElement-to-OctetString in SEC 1)
+pub fn int_to_bytes(x: &BigInt, len: usize) -> Vec<u8> {
+    let (_, bytes) = x.to_bytes_be();
+    let mut out = vec![0; len.saturating_sub(bytes.len())];
+    out.extend_from_slice(&bytes[bytes.len().saturating_sub(len)..]);
+    out
+}
+
+// Width-w non-adjacent form of a non-negative k, least significant digit first.
+// Every non-zero digit is odd and below 2^(w-1) in absolute value, and any w
+// consecutive digits contain at most one non-zero digit.
+pub fn wnaf(k: &BigInt, w: u32) -> Vec<i64> {
+    let window = 1i64 << w;
+    let mask = BigInt::from(window - 1);
+    let mut k = k.clone();
+    let mut digits = Vec::with_capacity(k.bits() as usize + 1);
+    while k > BigInt::zero() {
+        let mut digit = 0;
+        if k.bit(0) {
+            digit = i64::try_from(&k & &mask).expect("masked to w bits");
+            if digit >= window / 2 {
+                digit -= window;
+            }
+            k -= digit;
+        }
+        digits.push(digit);
+        k >>= 1;
+    }
+    digits
+}
+
+// Joint sparse form of non-negative k0 and k1 (Guide to Elliptic Curve Cryptography,
+// algorithm 3.50), least significant digit pair first. Digits are in {-1, 0, 1} and
+// on average half of the pairs are (0, 0).
+pub fn jsf(k0: &BigInt, k1: &BigInt) -> Vec<(i8, i8)> {
+    let (mut k0, mut k1) = (k0.clone(), k1.clone());
+    let (mut d0, mut d1) = (0i64, 0i64);
+    let mut digits = Vec::new();
+    let low_bits = |k: &BigInt| (0..3).fold(0i64, |acc, i| acc | (i64::from(k.bit(i)) << i));
+    while k0 > BigInt::zero() || k1 > BigInt::zero() || d0 > 0 || d1 > 0 {
+        // l_i mod 8 where l_i = d_i + k_i
+        let l0 = (low_bits(&k0) + d0) % 8;
+        let l1 = (low_bits(&k1) + d1) % 8;
+        let digit = |l: i64, other: i64| -> i64 {
+            if l % 2 == 0 {
+                return 0;
+            }
+            let u = if l % 4 == 1 { 1 } else { -1 };
+            if (l == 3 || l == 5) && other % 4 == 2 {
+                -u
+            } else {
+                u
+            }
+        };
+        let (u0, u1) = (digit(l0, l1), digit(l1, l0));
+        if 2 * d0 == 1 + u0 {
+            d0 = 1 - d0;
+        }
+        if 2 * d1 == 1 + u1 {
+            d1 = 1 - d1;
+        }
+        digits.push((u0 as i8, u1 as i8));
+        k0 >>= 1;
+        k1 >>= 1;
+    }
+    digits
+}
+
+// Inverts every value with a single field inversion (Montgomery's trick): the
+// running products are inverted once and unwound from the back
+pub fn batch_invert<'f>(values: &[FieldElement<'f>]) -> Result<Vec<FieldElement<'f>>, Error> {
+    let Some(first) = values.first() else {
+        return Ok(Vec::new());
+    };
+    let mut products = Vec::with_capacity(values.len());
+    let mut product = first.field().one();
+    for value in values {
+        product = &product * value;
+        products.push(product.clone());
+    }
+    let mut inverse = product.invert()?;
+    let mut inverses = vec![first.field().zero(); values.len()];
+    for i in (1..values.len()).rev() {
+        inverses[i] = &inverse * &products[i - 1];
+        inverse = &inverse * &values[i];
+    }
+    inverses[0] = inverse;
+    Ok(inverses)
+}
+
+// HMAC of the concatenation of data under key, for any hash function D
+pub fn hmac<D: Digest + BlockSizeUser>(key: &[u8], data: &[&[u8]]) -> Vec<u8> {
+    let mut mac =
+        <SimpleHmac<D> as Mac>::new_from_slice(key).expect("HMAC accepts keys of any size");
+    for chunk in data {
+        mac.update(chunk);
     }
-    None
+    mac.finalize().into_bytes().to_vec()
 }
@@ -1,11 +1,18 @@
 #[cfg(test)]
 mod tests {
-    use tiny_ec::curve_registry::get_curve;
+    use num_bigint::BigInt;
+    use tiny_ec::curve::{Curve, SubGroup};
+    use tiny_ec::curve_registry::{
+        curve_aliases, curve_oid, from_twisted, get_curve, get_curve_by_oid, list_curves,
+        load_curves, load_curves_from_str, register_curve, to_twisted, twist_constant, CurveFormat,
+    };
+    use tiny_ec::point::Point;
+    use tiny_ec::Error;
 
     #[test]
     fn test_invalid_curve_name() {
         let result = get_curve("abcd");
-        assert!(result.is_err());
+        assert!(matches!(result, Err(Error::UnknownCurve)));
     }
 
     #[test]
@@ -18,4 +25,213 @@ mod tests {
         let curve = result.unwrap();
         assert_eq!(curve_name, curve.name);
     }
+
+    #[test]
+    fn test_list_curves() {
+        let names = list_curves();
+        assert!(names.len() >= 23);
+        assert!(names.contains(&String::from("secp256r1")));
+        assert!(names.contains(&String::from("brainpoolP512t1")));
+        assert!(names.iter().all(|name| get_curve(name).is_ok()));
+    }
+
+    #[test]
+    fn test_lookup_by_alias() {
+        let curve = get_curve("secp256r1").unwrap();
+        for alias in ["P-256", "prime256v1", "SECP256R1", "nistp256"] {
+            let aliased = get_curve(alias).unwrap();
+            assert!(aliased == curve);
+            assert_eq!(aliased.name, "secp256r1");
+        }
+        assert_eq!(curve_aliases("p-384").unwrap(), &["P-384", "nistp384"][..]);
+        assert_eq!(curve_aliases("brainpoolP160r1").unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_lookup_by_oid() {
+        let curve = get_curve_by_oid("1.2.840.10045.3.1.7").unwrap();
+        assert_eq!(curve.name, "secp256r1");
+        assert_eq!(get_curve_by_oid("1.3.132.0.10").unwrap().name, "secp256k1");
+        assert_eq!(
+            get_curve_by_oid("1.3.36.3.3.2.8.1.1.14").unwrap().name,
+            "brainpoolP512t1"
+        );
+        assert!(matches!(
+            get_curve_by_oid("1.2.3.4"),
+            Err(Error::UnknownCurve)
+        ));
+
+        for name in list_curves() {
+            if let Some(oid) = curve_oid(&name) {
+                assert_eq!(get_curve_by_oid(&oid).unwrap().name, name);
+            }
+        }
+        assert_eq!(curve_oid("P-521").unwrap(), "1.3.132.0.35");
+    }
+
+    #[test]
+    fn test_generator_on_curve() {
+        for name in list_curves() {
+            let curve = get_curve(&name).unwrap();
+            assert!(!curve.is_singular(), "{} is singular", name);
+            assert!(
+                curve.on_curve(&curve.field.g.0, &curve.field.g.1),
+                "generator of {} is not on the curve",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_generator_order() {
+        for name in list_curves() {
+            let curve = get_curve(&name).unwrap();
+            let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+            assert_eq!(
+                Point::mul_double_and_add(&g, curve.field.n.clone()).unwrap(),
+                Point::inf(&curve),
+                "n·G is not the point at infinity on {}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_brainpool_twists() {
+        for bits in [160, 192, 224, 256, 320, 384, 512] {
+            let curve = get_curve(&format!("brainpoolP{}r1", bits)).unwrap();
+            let twisted = get_curve(&format!("brainpoolP{}t1", bits)).unwrap();
+            let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+            let g_t = Point::new(
+                &twisted,
+                twisted.field.g.0.clone(),
+                twisted.field.g.1.clone(),
+            )
+            .unwrap();
+
+            assert_eq!(to_twisted(&g, &twisted).unwrap(), g_t);
+            assert_eq!(from_twisted(&g_t, &curve).unwrap(), g);
+
+            let q = Point::mul_double_and_add(&g, BigInt::from(1337)).unwrap();
+            let q_t = Point::mul_double_and_add(&g_t, BigInt::from(1337)).unwrap();
+            assert_eq!(to_twisted(&q, &twisted).unwrap(), q_t);
+        }
+    }
+
+    #[test]
+    fn test_twist_constant_unknown_curve() {
+        assert_eq!(twist_constant("secp256r1"), Err(Error::NoTwist));
+        assert_eq!(twist_constant("brainpoolP161r1"), Err(Error::NoTwist));
+    }
+
+    #[test]
+    fn test_load_toml_curves() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/toy_curves.toml");
+        assert_eq!(load_curves(path).unwrap(), vec!["toy997", "toy251"]);
+
+        let curve = get_curve("toy-prime-order").unwrap();
+        assert_eq!(curve.name, "toy997");
+        assert_eq!(curve.field.n, BigInt::from(997));
+        assert_eq!(
+            get_curve_by_oid("1.3.6.1.4.1.99999.1").unwrap().name,
+            "toy997"
+        );
+
+        let curve = get_curve("toy251").unwrap();
+        assert_eq!(curve.field.p, BigInt::from(1009));
+        assert_eq!(curve.field.h, BigInt::from(4));
+        assert!(list_curves().contains(&String::from("toy251")));
+
+        assert_eq!(load_curves(path), Err(Error::DuplicateCurve));
+    }
+
+    #[test]
+    fn test_load_json_curves() {
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/data/toy_curves.json");
+        assert_eq!(load_curves(path).unwrap(), vec!["toy997json", "cryptoHack"]);
+
+        let curve = get_curve("cryptohack").unwrap();
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+        let expected = Point::new(&curve, BigInt::from(2407), BigInt::from(4409)).unwrap();
+        assert_eq!(g, expected);
+    }
+
+    #[test]
+    fn test_reject_invalid_curve_definitions() {
+        let off_curve = r#"
+            [[curves]]
+            name = "badGenerator"
+            p = 1009
+            a = 1
+            b = 24
+            g = [3, 488]
+            n = 997
+        "#;
+        assert!(matches!(
+            load_curves_from_str(off_curve, CurveFormat::Toml),
+            Err(Error::InvalidCurve(report)) if !report.generator_on_curve
+        ));
+
+        let wrong_order = r#"{"curves": [
+            {"name": "badOrder", "p": 1009, "a": 1, "b": 24, "g": [3, 487], "n": 991}
+        ]}"#;
+        assert!(load_curves_from_str(wrong_order, CurveFormat::Json).is_err());
+
+        let singular = r#"{"curves": [
+            {"name": "singular", "p": 23, "a": 0, "b": 0, "g": [1, 1], "n": 23}
+        ]}"#;
+        assert!(load_curves_from_str(singular, CurveFormat::Json).is_err());
+
+        let zero_modulus = r#"{"curves": [
+            {"name": "zeroModulus", "p": 0, "a": 1, "b": 24, "g": [3, 487], "n": 997}
+        ]}"#;
+        assert!(matches!(
+            load_curves_from_str(zero_modulus, CurveFormat::Json),
+            Err(Error::MalformedCurveFile(_))
+        ));
+
+        let zero_order = r#"{"curves": [
+            {"name": "zeroOrder", "p": 1009, "a": 1, "b": 24, "g": [3, 487], "n": 0}
+        ]}"#;
+        assert!(matches!(
+            load_curves_from_str(zero_order, CurveFormat::Json),
+            Err(Error::InvalidCurve(report)) if !report.generator_has_order_n
+        ));
+
+        let shadowing = r#"{"curves": [
+            {"name": "toyShadow", "aliases": ["P-256"], "p": 1009, "a": 1, "b": 24,
+             "g": [3, 487], "n": 997}
+        ]}"#;
+        assert_eq!(
+            load_curves_from_str(shadowing, CurveFormat::Json),
+            Err(Error::DuplicateCurve)
+        );
+        assert!(get_curve("toyShadow").is_err());
+
+        assert!(matches!(
+            load_curves_from_str("not a curve file", CurveFormat::Toml),
+            Err(Error::MalformedCurveFile(_))
+        ));
+        assert_eq!(load_curves("curves.yaml"), Err(Error::UnsupportedFormat));
+        assert!(get_curve("badGenerator").is_err());
+    }
+
+    #[test]
+    fn test_register_curve() {
+        let field = SubGroup {
+            p: BigInt::from(1009),
+            g: (BigInt::from(506), BigInt::from(72)),
+            n: BigInt::from(251),
+            h: BigInt::from(4),
+        };
+        let curve = Curve::new(
+            BigInt::from(1),
+            BigInt::from(8),
+            field,
+            "registeredToy".to_string(),
+        );
+        register_curve(curve, vec![String::from("regToy")], None).unwrap();
+        assert_eq!(get_curve("REGTOY").unwrap().name, "registeredToy");
+        assert_eq!(curve_oid("regToy"), None);
+    }
 }
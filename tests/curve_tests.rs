@@ -2,6 +2,8 @@
 mod tests {
     use num_bigint::BigInt;
     use tiny_ec::curve::{Curve, SubGroup};
+    use tiny_ec::point::Point;
+    use tiny_ec::Error;
 
     #[test]
     fn test_curve_is_singular() {
@@ -11,38 +13,330 @@ mod tests {
             n: BigInt::from(5),
             h: BigInt::from(1),
         };
-        let curve1 = Curve {
-            a: BigInt::from(0),
-            b: BigInt::from(0),
-            field: field.clone(),
-            name: String::from("test_curve"),
-        };
+        let curve1 = Curve::new(
+            BigInt::from(0),
+            BigInt::from(0),
+            field.clone(),
+            String::from("test_curve"),
+        );
         assert!(curve1.is_singular());
 
-        let curve2 = Curve {
-            a: BigInt::from(-3),
-            b: BigInt::from(2),
-            field: field.clone(),
-            name: String::from("test_curve"),
-        };
+        let curve2 = Curve::new(
+            BigInt::from(-3),
+            BigInt::from(2),
+            field.clone(),
+            String::from("test_curve"),
+        );
         assert!(curve2.is_singular());
 
-        let curve3 = Curve {
-            a: BigInt::from(-3),
-            b: BigInt::from(1),
-            field: field.clone(),
-            name: String::from("test_curve"),
-        };
+        let curve3 = Curve::new(
+            BigInt::from(-3),
+            BigInt::from(1),
+            field.clone(),
+            String::from("test_curve"),
+        );
         assert!(!curve3.is_singular());
 
-        let curve4 = Curve {
-            a: BigInt::from(2),
-            b: BigInt::from(2),
+        let curve4 = Curve::new(
+            BigInt::from(2),
+            BigInt::from(2),
             field,
-            name: String::from("test_curve"),
-        };
+            String::from("test_curve"),
+        );
         assert!(!curve4.is_singular());
     }
+
+    fn toy_curve(p: u32, a: i32, b: u32, g: (u32, u32), n: u32, h: u32) -> Curve {
+        let field = SubGroup {
+            p: BigInt::from(p),
+            g: (BigInt::from(g.0), BigInt::from(g.1)),
+            n: BigInt::from(n),
+            h: BigInt::from(h),
+        };
+        Curve::new(
+            BigInt::from(a),
+            BigInt::from(b),
+            field,
+            String::from("test_curve"),
+        )
+    }
+
+    #[test]
+    fn test_validate_registry_curves() {
+        for name in tiny_ec::curve_registry::list_curves() {
+            let curve = tiny_ec::curve_registry::get_curve(&name).unwrap();
+            let report = curve.validate();
+            assert!(report.is_valid(), "{}: {}", name, report);
+        }
+    }
+
+    #[test]
+    fn test_validate_toy_curve() {
+        let curve = toy_curve(1009, 1, 24, (3, 487), 997, 1);
+        let report = curve.validate();
+        assert!(report.is_well_formed());
+        assert!(!report.embedding_degree_above_threshold);
+        assert_eq!(report.failures(), vec!["MOV embedding degree is too small"]);
+        assert!(curve.validate_with_mov_threshold(3).is_valid());
+    }
+
+    #[test]
+    fn test_validate_detects_bad_parameters() {
+        let curve = toy_curve(1009, 1, 24, (3, 488), 997, 1);
+        let report = curve.validate();
+        assert!(!report.generator_on_curve);
+        assert!(!report.generator_has_order_n);
+
+        let curve = toy_curve(1009, 1, 24, (3, 487), 991, 1);
+        let report = curve.validate();
+        assert!(report.n_is_prime);
+        assert!(!report.generator_has_order_n);
+
+        let curve = toy_curve(1009, -1008, 24, (3, 487), 997, 1);
+        assert!(!curve.validate().coefficients_reduced);
+
+        let curve = toy_curve(1011, 1, 24, (3, 487), 997, 1);
+        assert!(!curve.validate().p_is_prime);
+
+        let curve = toy_curve(1009, 1, 24, (3, 487), 997, 2);
+        assert!(!curve.validate().cofactor_in_hasse_bound);
+    }
+
+    #[test]
+    fn test_validate_security_checks() {
+        // #E = p for y^2 = x^3 + 2x + 45 over F_1009
+        let anomalous = toy_curve(1009, 2, 45, (1, 413), 1009, 1);
+        let report = anomalous.validate_with_mov_threshold(3);
+        assert!(report.is_well_formed());
+        assert!(!report.not_anomalous);
+        assert!(!report.is_secure());
+
+        // y^2 = x^3 + x over F_1019 is supersingular with embedding degree 2
+        let supersingular = toy_curve(1019, 1, 0, (568, 136), 17, 60);
+        let report = supersingular.validate_with_mov_threshold(3);
+        assert!(report.is_well_formed());
+        assert!(!report.embedding_degree_above_threshold);
+        assert!(report.not_anomalous);
+    }
+
+    #[test]
+    fn test_new_checked() {
+        let p256 = tiny_ec::curve_registry::get_curve("P-256").unwrap();
+        let checked = Curve::new_checked(
+            p256.a.clone(),
+            p256.b.clone(),
+            p256.field.clone(),
+            p256.name.clone(),
+        );
+        assert!(checked.is_ok());
+
+        let result = Curve::new_checked(
+            p256.a.clone(),
+            &p256.b + 1,
+            p256.field.clone(),
+            p256.name.clone(),
+        );
+        match result {
+            Err(Error::InvalidCurve(report)) => {
+                assert!(!report.generator_on_curve);
+                assert!(report.non_singular);
+            }
+            _ => panic!("expected an invalid curve error"),
+        }
+    }
+
+    #[test]
+    fn test_new_checked_rejects_degenerate_moduli() {
+        let field = |p: i64, n: i64| SubGroup {
+            p: BigInt::from(p),
+            g: (BigInt::from(0), BigInt::from(0)),
+            n: BigInt::from(n),
+            h: BigInt::from(1),
+        };
+        let new_checked = |field| {
+            Curve::new_checked(
+                BigInt::from(0),
+                BigInt::from(0),
+                field,
+                "degenerate".to_string(),
+            )
+        };
+
+        match new_checked(field(0, 5)) {
+            Err(Error::InvalidCurve(report)) => {
+                assert!(!report.p_is_prime);
+                assert!(!report.non_singular);
+                assert!(!report.generator_on_curve);
+                assert!(!report.embedding_degree_above_threshold);
+            }
+            _ => panic!("expected an invalid curve error"),
+        }
+        // Curves are built before they are validated and must not panic either way
+        let degenerate = Curve::new(
+            BigInt::from(0),
+            BigInt::from(0),
+            field(0, 5),
+            "degenerate".to_string(),
+        );
+        assert_eq!(
+            Point::new(&degenerate, BigInt::from(0), BigInt::from(0)),
+            Err(Error::NotOnCurve)
+        );
+        let inf = Point::inf(&degenerate);
+        assert!(Point::mul_double_and_add(&inf, BigInt::from(3))
+            .unwrap()
+            .is_infinity());
+
+        match new_checked(field(23, 0)) {
+            Err(Error::InvalidCurve(report)) => {
+                assert!(!report.n_is_prime);
+                assert!(!report.generator_has_order_n);
+                assert!(!report.embedding_degree_above_threshold);
+            }
+            _ => panic!("expected an invalid curve error"),
+        }
+    }
+
+    #[test]
+    fn test_registry_endomorphisms() {
+        for name in ["secp160k1", "secp192k1", "secp224k1", "secp256k1"] {
+            let curve = tiny_ec::curve_registry::get_curve(name).unwrap();
+            let endomorphism = curve.endomorphism().unwrap().clone();
+            let plain = Curve::new(
+                curve.a.clone(),
+                curve.b.clone(),
+                curve.field.clone(),
+                curve.name.clone(),
+            );
+            assert!(plain.endomorphism().is_none());
+            assert!(
+                plain.with_endomorphism(endomorphism.clone()).is_ok(),
+                "{}",
+                name
+            );
+
+            let n = &curve.field.n;
+            let half = n.bits() / 2 + 2;
+            for k in [BigInt::from(0), n - 1, n >> 3u32, BigInt::from(12345)] {
+                let (k1, k2) = endomorphism.decompose(&k, n);
+                assert_eq!((&k1 + &k2 * &endomorphism.lambda - &k) % n, BigInt::from(0));
+                assert!(k1.bits() <= half && k2.bits() <= half, "{}", name);
+            }
+        }
+        assert!(tiny_ec::curve_registry::get_curve("P-256")
+            .unwrap()
+            .endomorphism()
+            .is_none());
+    }
+
+    #[test]
+    fn test_invalid_endomorphism() {
+        let k1 = tiny_ec::curve_registry::get_curve("secp256k1").unwrap();
+        let endomorphism = k1.endomorphism().unwrap().clone();
+        let plain = || {
+            Curve::new(
+                k1.a.clone(),
+                k1.b.clone(),
+                k1.field.clone(),
+                k1.name.clone(),
+            )
+        };
+
+        // The other cube root of unity modulo n belongs to the other β
+        let mut wrong_lambda = endomorphism.clone();
+        wrong_lambda.lambda = (&endomorphism.lambda * &endomorphism.lambda) % &k1.field.n;
+        let result = plain().with_endomorphism(wrong_lambda);
+        assert!(matches!(result, Err(Error::InvalidEndomorphism)));
+
+        let mut trivial_beta = endomorphism.clone();
+        trivial_beta.beta = BigInt::from(1);
+        let result = plain().with_endomorphism(trivial_beta);
+        assert!(matches!(result, Err(Error::InvalidEndomorphism)));
+
+        let mut bad_basis = endomorphism.clone();
+        bad_basis.basis[0].0 += 1;
+        let result = plain().with_endomorphism(bad_basis);
+        assert!(matches!(result, Err(Error::InvalidEndomorphism)));
+
+        let p256 = tiny_ec::curve_registry::get_curve("P-256").unwrap();
+        let result = p256.with_endomorphism(endomorphism);
+        assert!(matches!(result, Err(Error::InvalidEndomorphism)));
+    }
+
+    #[test]
+    fn test_field_backends_agree_on_scalar_multiplication() {
+        use tiny_ec::field_element::FieldBackend;
+
+        let k = BigInt::parse_bytes(b"c0ffee5eed15dec0de0f1ee7babe", 16).unwrap();
+        for name in [
+            "secp192r1",
+            "secp224r1",
+            "P-256",
+            "secp256k1",
+            "P-384",
+            "P-521",
+        ] {
+            let curve = tiny_ec::curve_registry::get_curve(name).unwrap();
+            let g = curve.field.g.clone();
+            let reference_curve = curve
+                .clone()
+                .with_field_backend(FieldBackend::BigInt)
+                .unwrap();
+            let reference_g = Point::new(&reference_curve, g.0.clone(), g.1.clone()).unwrap();
+            let expected = Point::mul_double_and_add(&reference_g, k.clone()).unwrap();
+            for backend in [
+                FieldBackend::Montgomery,
+                FieldBackend::Solinas,
+                FieldBackend::PseudoMersenne,
+            ] {
+                if let Ok(limb_curve) = curve.clone().with_field_backend(backend) {
+                    let limb_g = Point::new(&limb_curve, g.0.clone(), g.1.clone()).unwrap();
+                    let result = Point::mul_double_and_add(&limb_g, k.clone()).unwrap();
+                    assert_eq!(
+                        (&result.x, &result.y),
+                        (&expected.x, &expected.y),
+                        "{}",
+                        name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_curves_with_different_backends_interoperate() {
+        use tiny_ec::field_element::FieldBackend;
+        use tiny_ec::key_pair::Keypair;
+        use tiny_ec::scalar::Scalar;
+
+        let curve = tiny_ec::curve_registry::get_curve("P-256").unwrap();
+        let reference_curve = curve
+            .clone()
+            .with_field_backend(FieldBackend::BigInt)
+            .unwrap();
+        assert!(curve == reference_curve);
+
+        let g = curve.field.g.clone();
+        let p = Point::new(&curve, g.0.clone(), g.1.clone()).unwrap();
+        let q = Point::new(&reference_curve, g.0.clone(), g.1.clone()).unwrap();
+        let one = Scalar::new(&curve, BigInt::from(1));
+        assert_eq!(Point::mul2(&p, &one, &q, &one).unwrap(), p.double());
+        assert_eq!(
+            Point::multiscalar_mul(&[(one.clone(), p.clone()), (one, q)]).unwrap(),
+            p.double()
+        );
+
+        let signer = Keypair::new(&reference_curve, Some(&BigInt::from(1337)), None).unwrap();
+        let verifier = Keypair::new(&curve, None, Some(&signer.public_key)).unwrap();
+        let signature = signer.sign(b"sample").unwrap();
+        assert!(verifier.verify(b"sample", &signature));
+
+        let other = tiny_ec::curve_registry::get_curve("secp256k1").unwrap();
+        assert!(matches!(
+            Keypair::new(&other, None, Some(&signer.public_key)),
+            Err(Error::CurveMismatch)
+        ));
+    }
 }
 
 // #[cfg(test)]
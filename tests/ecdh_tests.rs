@@ -0,0 +1,106 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use sha2::{Digest, Sha256};
+    use tiny_ec::curve::{Curve, SubGroup};
+    use tiny_ec::ecdh::{hkdf, x963_kdf};
+    use tiny_ec::key_pair::Keypair;
+    use tiny_ec::point::Point;
+    use tiny_ec::Error;
+
+    fn cofactor_curve() -> Curve {
+        // y^2 = x^3 + 2x + 3 over F_97 has 100 points, (24, 2) generates the subgroup of order 25
+        let field = SubGroup {
+            p: BigInt::from(97),
+            g: (BigInt::from(24), BigInt::from(2)),
+            n: BigInt::from(25),
+            h: BigInt::from(4),
+        };
+        Curve::new(
+            BigInt::from(2),
+            BigInt::from(3),
+            field,
+            "testCurve".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_shared_secret_agreement() {
+        let curve = tiny_ec::curve_registry::get_curve("brainpoolP160r1").unwrap();
+        let alice = Keypair::new(&curve, Some(&BigInt::from(1337)), None).unwrap();
+        let bob = Keypair::new(&curve, Some(&BigInt::from(7331)), None).unwrap();
+
+        let secret_a = alice.ecdh(&bob.public_key).unwrap();
+        let secret_b = bob.ecdh(&alice.public_key).unwrap();
+        assert_eq!(secret_a, secret_b);
+        assert_eq!(secret_a.as_bytes().len(), 20);
+
+        let key_a = secret_a.derive_hkdf::<Sha256>(b"", b"tiny_ec", 32).unwrap();
+        let key_b = secret_b.derive_hkdf::<Sha256>(b"", b"tiny_ec", 32).unwrap();
+        assert_eq!(key_a, key_b);
+    }
+
+    #[test]
+    fn test_ecdh_requires_private_key() {
+        let curve = tiny_ec::curve_registry::get_curve("secp192r1").unwrap();
+        let alice = Keypair::new(&curve, Some(&BigInt::from(1337)), None).unwrap();
+        let public_only = Keypair::new(&curve, None, Some(&alice.public_key)).unwrap();
+
+        assert_eq!(
+            public_only.ecdh(&alice.public_key),
+            Err(Error::MissingPrivateKey)
+        );
+    }
+
+    #[test]
+    fn test_ecdh_rejects_invalid_peers() {
+        let curve = cofactor_curve();
+        let alice = Keypair::new(&curve, Some(&BigInt::from(7)), None).unwrap();
+
+        assert_eq!(alice.ecdh(&Point::inf(&curve)), Err(Error::PointAtInfinity));
+
+        let small_order = Point::new(&curve, BigInt::from(96), BigInt::from(0)).unwrap();
+        assert_eq!(alice.ecdh(&small_order), Err(Error::NotInSubgroup));
+
+        let other_curve = tiny_ec::curve_registry::get_curve("secp192r1").unwrap();
+        let other = Keypair::new(&other_curve, Some(&BigInt::from(7)), None).unwrap();
+        assert_eq!(alice.ecdh(&other.public_key), Err(Error::CurveMismatch));
+
+        let bob = Keypair::new(&curve, Some(&BigInt::from(11)), None).unwrap();
+        assert!(alice.ecdh(&bob.public_key).is_ok());
+    }
+
+    #[test]
+    fn test_hkdf_rfc5869_vector() {
+        // RFC 5869 A.1
+        let ikm = [0x0b; 22];
+        let salt = hex::decode("000102030405060708090a0b0c").unwrap();
+        let info = hex::decode("f0f1f2f3f4f5f6f7f8f9").unwrap();
+        let okm = hkdf::<Sha256>(&ikm, &salt, &info, 42).unwrap();
+        assert_eq!(
+            hex::encode(okm),
+            "3cb25f25faacd57a90434f64d0362f2a2d2d0a90cf1a5a4c5db02d56ecc4c5bf34007208d5b887185865"
+        );
+        assert_eq!(
+            hkdf::<Sha256>(&ikm, &salt, &info, 255 * 32 + 1),
+            Err(Error::InvalidKeyLength)
+        );
+    }
+
+    #[test]
+    fn test_x963_kdf() {
+        let z = [0x42; 24];
+        let key = x963_kdf::<Sha256>(&z, b"info", 48).unwrap();
+
+        let mut block1 = Sha256::new();
+        block1.update(z);
+        block1.update([0, 0, 0, 1]);
+        block1.update(b"info");
+        let mut block2 = Sha256::new();
+        block2.update(z);
+        block2.update([0, 0, 0, 2]);
+        block2.update(b"info");
+        let expected = [block1.finalize().to_vec(), block2.finalize()[..16].to_vec()].concat();
+        assert_eq!(key, expected);
+    }
+}
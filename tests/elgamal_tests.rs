@@ -0,0 +1,76 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use tiny_ec::elgamal::{decode_message, encode_message, max_message_len};
+    use tiny_ec::key_pair::Keypair;
+    use tiny_ec::point::Point;
+    use tiny_ec::Error;
+
+    #[test]
+    fn test_encrypt_decrypt_point() {
+        let curve = tiny_ec::curve_registry::get_curve("brainpoolP160r1").unwrap();
+        let keypair = Keypair::new(&curve, Some(&BigInt::from(1337)), None).unwrap();
+        assert!(keypair.encrypts);
+
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+        let m = Point::mul_double_and_add(&g, BigInt::from(42)).unwrap();
+        let ciphertext = keypair.encrypt(&m).unwrap();
+        assert_ne!(ciphertext.c2, m);
+        assert_eq!(keypair.decrypt(&ciphertext).unwrap(), m);
+    }
+
+    #[test]
+    fn test_encrypt_decrypt_bytes() {
+        let curve = tiny_ec::curve_registry::get_curve("secp192r1").unwrap();
+        let keypair = Keypair::new(&curve, Some(&BigInt::from(1337)), None).unwrap();
+        let public_only = Keypair::new(&curve, None, Some(&keypair.public_key)).unwrap();
+        assert!(!public_only.encrypts);
+
+        let msg = b"\x00\x00hello";
+        let ciphertext = public_only.encrypt_bytes(msg).unwrap();
+        assert_eq!(keypair.decrypt_bytes(&ciphertext).unwrap(), msg);
+        assert_eq!(
+            public_only.decrypt_bytes(&ciphertext),
+            Err(Error::MissingPrivateKey)
+        );
+    }
+
+    #[test]
+    fn test_message_encoding() {
+        let curve = tiny_ec::curve_registry::get_curve("brainpoolP160r1").unwrap();
+        let max_len = max_message_len(&curve);
+        assert_eq!(max_len, 18);
+
+        let msg = vec![0xff; max_len];
+        let point = encode_message(&curve, &msg).unwrap();
+        assert_eq!(decode_message(&point).unwrap(), msg);
+        assert_eq!(
+            decode_message(&Point::inf(&curve)),
+            Err(Error::InvalidEncoding)
+        );
+        assert_eq!(
+            encode_message(&curve, &vec![0xff; max_len + 1]),
+            Err(Error::MessageTooLong)
+        );
+    }
+
+    #[test]
+    fn test_homomorphic_addition() {
+        let curve = tiny_ec::curve_registry::get_curve("brainpoolP160r1").unwrap();
+        let keypair = Keypair::new(&curve, Some(&BigInt::from(1337)), None).unwrap();
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+
+        let votes = [1, 0, 1, 1];
+        let tally = votes
+            .iter()
+            .map(|v| {
+                let m = Point::mul_double_and_add(&g, BigInt::from(*v)).unwrap();
+                keypair.encrypt(&m).unwrap()
+            })
+            .reduce(|acc, c| acc.add(&c).unwrap())
+            .unwrap();
+
+        let expected = Point::mul_double_and_add(&g, BigInt::from(3)).unwrap();
+        assert_eq!(keypair.decrypt(&tally).unwrap(), expected);
+    }
+}
@@ -0,0 +1,185 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::{BigInt, RandBigInt};
+    use num_traits::{Euclid, Num};
+    use tiny_ec::curve_registry::{get_curve, list_curves};
+    use tiny_ec::field_element::{FieldBackend, FieldElement, PrimeField};
+    use tiny_ec::Error;
+
+    fn p256() -> BigInt {
+        BigInt::from_str_radix(
+            "ffffffff00000001000000000000000000000000ffffffffffffffffffffffff",
+            16,
+        )
+        .unwrap()
+    }
+
+    const BACKENDS: [FieldBackend; 4] = [
+        FieldBackend::BigInt,
+        FieldBackend::Montgomery,
+        FieldBackend::Solinas,
+        FieldBackend::PseudoMersenne,
+    ];
+
+    fn check_against_bigint(p: &BigInt) {
+        check_field_against_bigint(&PrimeField::new(p.clone()).unwrap());
+    }
+
+    // Compares every operation against plain BigInt arithmetic modulo p, on random
+    // operands as well as the edge values 0, 1 and p - 1
+    fn check_field_against_bigint(fp: &PrimeField) {
+        let p = fp.modulus();
+        let mut rng = rand::thread_rng();
+        let bound: BigInt = p * 2;
+        let edges = [BigInt::from(0), BigInt::from(1), p - 1];
+        let operands = (0..50)
+            .map(|_| {
+                (
+                    rng.gen_bigint_range(&-&bound, &bound),
+                    rng.gen_bigint_range(&-&bound, &bound),
+                )
+            })
+            .chain(
+                edges
+                    .iter()
+                    .flat_map(|a| edges.iter().map(|b| (a.clone(), b.clone()))),
+            );
+        for (a, b) in operands {
+            let (fa, fb) = (fp.element(&a), fp.element(&b));
+
+            assert_eq!(fa.to_bigint(), a.rem_euclid(p));
+            assert_eq!((&fa + &fb).to_bigint(), (&a + &b).rem_euclid(p));
+            assert_eq!((&fa - &fb).to_bigint(), (&a - &b).rem_euclid(p));
+            assert_eq!((&fa * &fb).to_bigint(), (&a * &b).rem_euclid(p));
+            assert_eq!((-&fa).to_bigint(), (-&a).rem_euclid(p));
+            assert_eq!(fa.square().to_bigint(), (&a * &a).rem_euclid(p));
+            assert_eq!((&fa * 3).to_bigint(), (&a * BigInt::from(3)).rem_euclid(p));
+            assert_eq!(
+                fa.pow(&BigInt::from(65537)).to_bigint(),
+                a.modpow(&BigInt::from(65537), p)
+            );
+            if !fa.is_zero() {
+                assert_eq!(&fa * &fa.invert().unwrap(), fp.one());
+            }
+        }
+    }
+
+    #[test]
+    fn test_small_prime() {
+        check_against_bigint(&BigInt::from(97));
+    }
+
+    #[test]
+    fn test_multi_limb_prime() {
+        check_against_bigint(&p256());
+        check_against_bigint(&((BigInt::from(1) << 521) - 1));
+    }
+
+    #[test]
+    fn test_even_modulus_fallback() {
+        check_against_bigint(&BigInt::from(2));
+    }
+
+    #[test]
+    fn test_every_backend_matches_bigint_on_registry_primes() {
+        let mut primes: Vec<BigInt> = list_curves()
+            .iter()
+            .map(|name| get_curve(name).unwrap().field.p)
+            .collect();
+        primes.sort();
+        primes.dedup();
+        for p in primes {
+            for backend in BACKENDS {
+                if let Ok(fp) = PrimeField::with_backend(p.clone(), backend) {
+                    assert_eq!(fp.backend(), backend);
+                    check_field_against_bigint(&fp);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_default_backend() {
+        let backend = |name: &str| get_curve(name).unwrap().base_field().backend();
+        for name in ["secp192r1", "secp224r1", "secp256r1", "secp384r1"] {
+            assert_eq!(backend(name), FieldBackend::Solinas);
+        }
+        assert_eq!(backend("secp256k1"), FieldBackend::PseudoMersenne);
+        assert_eq!(backend("brainpoolP256r1"), FieldBackend::Montgomery);
+        assert_eq!(backend("secp521r1"), FieldBackend::Montgomery);
+        assert_eq!(
+            PrimeField::new(BigInt::from(2)).unwrap().backend(),
+            FieldBackend::BigInt
+        );
+    }
+
+    #[test]
+    fn test_unsupported_backend() {
+        let unsupported = |p: BigInt, backend| {
+            assert_eq!(
+                PrimeField::with_backend(p, backend).unwrap_err(),
+                Error::UnsupportedBackend
+            )
+        };
+        unsupported(BigInt::from(97), FieldBackend::Solinas);
+        unsupported(p256(), FieldBackend::PseudoMersenne);
+        unsupported(BigInt::from(2), FieldBackend::Montgomery);
+        unsupported(BigInt::from(1) << 600, FieldBackend::Montgomery);
+    }
+
+    #[test]
+    fn test_invalid_modulus() {
+        for p in [BigInt::from(0), BigInt::from(1), BigInt::from(-7)] {
+            assert_eq!(
+                PrimeField::new(p.clone()).unwrap_err(),
+                Error::InvalidModulus
+            );
+            assert_eq!(
+                PrimeField::with_backend(p, FieldBackend::BigInt).unwrap_err(),
+                Error::InvalidModulus
+            );
+        }
+    }
+
+    #[test]
+    fn test_conditional_swap() {
+        for backend in [FieldBackend::BigInt, FieldBackend::Solinas] {
+            let fp = PrimeField::with_backend(p256(), backend).unwrap();
+            let (one, two) = (fp.element(&BigInt::from(1)), fp.element(&BigInt::from(2)));
+            let (mut a, mut b) = (one.clone(), two.clone());
+            FieldElement::conditional_swap(&mut a, &mut b, false);
+            assert_eq!((&a, &b), (&one, &two));
+            FieldElement::conditional_swap(&mut a, &mut b, true);
+            assert_eq!((&a, &b), (&two, &one));
+        }
+    }
+
+    #[test]
+    fn test_inverse_of_zero() {
+        let fp = PrimeField::new(BigInt::from(97)).unwrap();
+        assert_eq!(fp.zero().invert(), Err(Error::NonInvertible));
+    }
+
+    #[test]
+    fn test_sqrt_and_legendre() {
+        let fp = PrimeField::new(p256()).unwrap();
+        let x = fp.element(&BigInt::from(1337));
+        let square = x.square();
+        assert_eq!(square.legendre(), 1);
+        let root = square.sqrt().unwrap();
+        assert!(root == x || root == -&x);
+
+        let non_residue = fp.element(&BigInt::from(-1));
+        assert_eq!(non_residue.legendre(), -1);
+        assert_eq!(non_residue.sqrt(), Err(Error::NotAResidue));
+        assert_eq!(fp.zero().legendre(), 0);
+    }
+
+    #[test]
+    fn test_equality_is_canonical() {
+        let fp = PrimeField::new(BigInt::from(97)).unwrap();
+        assert_eq!(fp.element(&BigInt::from(-1)), fp.element(&BigInt::from(96)));
+        assert_eq!(fp.element(&BigInt::from(97)), fp.zero());
+        assert_eq!(fp.element(&BigInt::from(195)).to_string(), "1");
+    }
+}
@@ -0,0 +1,167 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use std::sync::Arc;
+    use std::thread;
+    use tiny_ec::curve::{Curve, SubGroup};
+    use tiny_ec::curve_registry::get_curve;
+    use tiny_ec::fixed_base::{generator_table, mul_generator, mul_generator_batch};
+    use tiny_ec::point::Point;
+    use tiny_ec::scalar::Scalar;
+    use tiny_ec::Error;
+
+    fn check_against_ladder(curve: &Curve) {
+        let g = Point::new(curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+        let n = &curve.field.n;
+        let mut scalars = vec![
+            BigInt::from(0),
+            BigInt::from(1),
+            BigInt::from(16),
+            n - 1,
+            n >> 1u32,
+        ];
+        scalars.extend((0..5).map(|_| Scalar::random(curve).value().clone()));
+        for k in scalars {
+            let expected = Point::mul_montgomery(&g, k.clone()).unwrap();
+            let k = Scalar::new(curve, k);
+            assert_eq!(
+                mul_generator(curve, &k).unwrap(),
+                expected,
+                "{}",
+                curve.name
+            );
+        }
+    }
+
+    #[test]
+    fn test_matches_ladder() {
+        for name in [
+            "secp192r1",
+            "secp256k1",
+            "P-256",
+            "brainpoolP320r1",
+            "P-521",
+        ] {
+            check_against_ladder(&get_curve(name).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_operation_count_does_not_depend_on_k() {
+        use tiny_ec::jacobian::operation_count;
+
+        for name in ["P-256", "secp256k1", "brainpoolP160r1"] {
+            let curve = get_curve(name).unwrap();
+            let n = &curve.field.n;
+            mul_generator(&curve, &Scalar::one(&curve)).unwrap();
+            let counts: Vec<u64> = [
+                BigInt::from(0),
+                BigInt::from(1),
+                BigInt::from(2),
+                n >> 100,
+                n - 1,
+            ]
+            .into_iter()
+            .map(|k| {
+                let k = Scalar::new(&curve, k);
+                let before = operation_count();
+                mul_generator(&curve, &k).unwrap();
+                operation_count() - before
+            })
+            .collect();
+            assert!(counts.iter().all(|count| *count == counts[0]), "{}", name);
+        }
+    }
+
+    #[test]
+    fn test_cached_by_parameters() {
+        let curve = get_curve("secp224r1").unwrap();
+        let table = generator_table(&curve).unwrap();
+        let mut renamed = get_curve("P-224").unwrap();
+        renamed.name = String::from("renamed");
+        assert!(Arc::ptr_eq(&table, &generator_table(&renamed).unwrap()));
+
+        let other = get_curve("secp224k1").unwrap();
+        assert!(!Arc::ptr_eq(&table, &generator_table(&other).unwrap()));
+
+        let mut cofactor = get_curve("P-224").unwrap();
+        cofactor.field.h = BigInt::from(2);
+        assert!(!Arc::ptr_eq(&table, &generator_table(&cofactor).unwrap()));
+    }
+
+    #[test]
+    fn test_shared_across_threads() {
+        let handles: Vec<_> = (0..4)
+            .map(|i| {
+                thread::spawn(move || {
+                    let curve = get_curve("brainpoolP256r1").unwrap();
+                    let k = Scalar::new(&curve, BigInt::from(1000 + i));
+                    let x = mul_generator(&curve, &k).unwrap().x;
+                    (x, generator_table(&curve).unwrap())
+                })
+            })
+            .collect();
+        let results: Vec<_> = handles.into_iter().map(|h| h.join().unwrap()).collect();
+        let curve = get_curve("brainpoolP256r1").unwrap();
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+        for (i, (x, table)) in results.iter().enumerate() {
+            let expected = Point::mul_double_and_add(&g, BigInt::from(1000 + i)).unwrap();
+            assert_eq!(x, &expected.x);
+            assert!(Arc::ptr_eq(table, &results[0].1));
+        }
+    }
+
+    #[test]
+    fn test_invalid_generator() {
+        let field = SubGroup {
+            p: BigInt::from(1009),
+            g: (BigInt::from(3), BigInt::from(488)),
+            n: BigInt::from(997),
+            h: BigInt::from(1),
+        };
+        let curve = Curve::new(
+            BigInt::from(1),
+            BigInt::from(24),
+            field,
+            String::from("bad"),
+        );
+        let k = Scalar::one(&curve);
+        assert_eq!(mul_generator(&curve, &k), Err(Error::NotOnCurve));
+    }
+
+    #[test]
+    fn test_even_order_falls_back() {
+        let field = SubGroup {
+            p: BigInt::from(1009),
+            g: (BigInt::from(3), BigInt::from(487)),
+            n: BigInt::from(998),
+            h: BigInt::from(1),
+        };
+        let curve = Curve::new(
+            BigInt::from(1),
+            BigInt::from(24),
+            field,
+            String::from("evenOrder"),
+        );
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+        assert!(matches!(generator_table(&curve), Err(Error::InvalidScalar)));
+        let ks: Vec<Scalar> = [2, 16, 500, 997]
+            .into_iter()
+            .map(|k| Scalar::new(&curve, BigInt::from(k)))
+            .collect();
+        let batch = mul_generator_batch(&curve, &ks).unwrap();
+        for (k, point) in ks.iter().zip(batch) {
+            let expected = Point::mul_double_and_add(&g, k.value().clone()).unwrap();
+            assert_eq!(mul_generator(&curve, k).unwrap(), expected);
+            assert_eq!(point, expected);
+        }
+    }
+
+    #[test]
+    fn test_curve_mismatch() {
+        let p256 = get_curve("P-256").unwrap();
+        let k1 = get_curve("secp256k1").unwrap();
+        let k = Scalar::one(&k1);
+        assert_eq!(mul_generator(&p256, &k), Err(Error::CurveMismatch));
+    }
+}
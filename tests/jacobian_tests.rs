@@ -0,0 +1,132 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use tiny_ec::curve::{Curve, SubGroup};
+    use tiny_ec::jacobian::JacobianPoint;
+    use tiny_ec::point::Point;
+
+    fn test_curve() -> Curve {
+        let field = SubGroup {
+            p: BigInt::from(97),
+            g: (BigInt::from(1), BigInt::from(2)),
+            n: BigInt::from(5),
+            h: BigInt::from(1),
+        };
+        Curve::new(
+            BigInt::from(2),
+            BigInt::from(3),
+            field,
+            "testCurve".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_roundtrip() {
+        let curve = test_curve();
+        let p1 = Point::new(&curve, BigInt::from(22), BigInt::from(5)).unwrap();
+        let j1 = JacobianPoint::from_affine(&p1);
+        assert_eq!(j1.to_affine().unwrap(), p1);
+
+        // (X·λ^2 : Y·λ^3 : λ) is the same point
+        let mut scaled = j1.clone();
+        scaled.x = &j1.x * 4;
+        scaled.y = &j1.y * 8;
+        scaled.z = j1.z.double();
+        assert_eq!(scaled, j1);
+        assert_eq!(scaled.to_affine().unwrap(), p1);
+    }
+
+    #[test]
+    fn test_add_and_double_match_affine() {
+        let curve = test_curve();
+        let p1 = Point::new(&curve, BigInt::from(22), BigInt::from(5)).unwrap();
+        let p2 = Point::new(&curve, BigInt::from(95), BigInt::from(31)).unwrap();
+        let j1 = JacobianPoint::from_affine(&p1);
+        let j2 = JacobianPoint::from_affine(&p2);
+
+        let sum = Point::add(&p1, &p2).unwrap();
+        assert_eq!(j1.add(&j2).to_affine().unwrap(), sum);
+        assert_eq!(j1.add_mixed(&p2).to_affine().unwrap(), sum);
+        assert_eq!(
+            j1.double().to_affine().unwrap(),
+            Point::add(&p1, &p1).unwrap()
+        );
+        assert_eq!(
+            j1.add(&j1).to_affine().unwrap(),
+            Point::add(&p1, &p1).unwrap()
+        );
+
+        // Sum of points with non-trivial Z coordinates
+        let j3 = j1.double();
+        let j4 = j2.double();
+        let expected = Point::add(
+            &Point::add(&p1, &p1).unwrap(),
+            &Point::add(&p2, &p2).unwrap(),
+        )
+        .unwrap();
+        assert_eq!(j3.add(&j4).to_affine().unwrap(), expected);
+    }
+
+    #[test]
+    fn test_infinity() {
+        let curve = test_curve();
+        let p1 = Point::new(&curve, BigInt::from(12), BigInt::from(3)).unwrap();
+        let p2 = Point::new(&curve, BigInt::from(12), BigInt::from(94)).unwrap();
+        let j1 = JacobianPoint::from_affine(&p1);
+        let inf = JacobianPoint::inf(&curve);
+
+        assert!(j1.add(&JacobianPoint::from_affine(&p2)).is_infinity());
+        assert!(j1.add_mixed(&p2).is_infinity());
+        assert_eq!(j1.add(&inf), j1);
+        assert_eq!(inf.add(&j1), j1);
+        assert_eq!(inf.add_mixed(&p1), j1);
+        assert!(inf.double().is_infinity());
+        assert_eq!(inf.to_affine().unwrap(), Point::inf(&curve));
+        assert!(JacobianPoint::from_affine(&Point::inf(&curve)).is_infinity());
+    }
+
+    #[test]
+    fn test_a_minus_3_doubling() {
+        let curve = tiny_ec::curve_registry::get_curve("P-256").unwrap();
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+        let mut j = JacobianPoint::from_affine(&g);
+        let mut affine = g.clone();
+        for _ in 0..8 {
+            j = j.double();
+            affine = Point::add(&affine, &affine).unwrap();
+        }
+        assert_eq!(j.to_affine().unwrap(), affine);
+    }
+
+    #[test]
+    fn test_scalar_multiplication_on_registry_curves() {
+        for name in ["brainpoolP256r1", "secp256k1", "secp384r1"] {
+            let curve = tiny_ec::curve_registry::get_curve(name).unwrap();
+            let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+            let mut expected = Point::inf(&curve);
+            for _ in 0..13 {
+                expected = Point::add(&expected, &g).unwrap();
+            }
+            assert_eq!(
+                Point::mul_double_and_add(&g, BigInt::from(13)).unwrap(),
+                expected
+            );
+        }
+    }
+
+    #[test]
+    fn test_batch_from_jacobian() {
+        let curve = test_curve();
+        let p1 = Point::new(&curve, BigInt::from(22), BigInt::from(5)).unwrap();
+        let mut points = vec![JacobianPoint::inf(&curve)];
+        let mut multiple = JacobianPoint::from_affine(&p1);
+        for _ in 0..6 {
+            points.push(multiple.clone());
+            multiple = multiple.add(&multiple.double());
+        }
+        points.push(JacobianPoint::inf(&curve));
+        let expected: Vec<Point> = points.iter().map(|p| p.to_affine().unwrap()).collect();
+        assert_eq!(Point::batch_from_jacobian(&points).unwrap(), expected);
+        assert!(Point::batch_from_jacobian(&[]).unwrap().is_empty());
+    }
+}
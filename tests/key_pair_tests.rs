@@ -1,15 +1,18 @@
 #[cfg(test)]
 mod tests {
     use num_bigint::BigInt;
+    use num_traits::Num;
     use tiny_ec::key_pair::Keypair;
     use tiny_ec::point::Point;
+    use tiny_ec::signature::Signature;
+    use tiny_ec::Error;
 
     #[test]
     fn test_error_without_keys() {
         let curve = tiny_ec::curve_registry::get_curve("brainpoolP160r1").unwrap();
 
         let result = Keypair::new(&curve, None, None);
-        assert!(result.is_none());
+        assert!(matches!(result, Err(Error::NoKeyMaterial)));
     }
 
     #[test]
@@ -24,4 +27,83 @@ mod tests {
         .unwrap();
         assert_eq!(keypair.public_key, expected_pub_key);
     }
+
+    #[test]
+    fn test_reject_private_keys_outside_range() {
+        let curve = tiny_ec::curve_registry::get_curve("secp256k1").unwrap();
+        let n = &curve.field.n;
+        for private_key in [BigInt::from(0), BigInt::from(-1), n.clone(), n + 1] {
+            assert!(matches!(
+                Keypair::new(&curve, Some(&private_key), None),
+                Err(Error::InvalidScalar)
+            ));
+            assert!(matches!(
+                Keypair::new_batch(&curve, &[BigInt::from(1), private_key]),
+                Err(Error::InvalidScalar)
+            ));
+        }
+        assert!(Keypair::new(&curve, Some(&(n - 1)), None).is_ok());
+    }
+
+    #[test]
+    fn test_new_batch() {
+        let curve = tiny_ec::curve_registry::get_curve("secp256k1").unwrap();
+        let private_keys: Vec<BigInt> = (1..=20).map(|i| BigInt::from(i) * 7919).collect();
+        let keypairs = Keypair::new_batch(&curve, &private_keys).unwrap();
+        assert_eq!(keypairs.len(), private_keys.len());
+        for (keypair, private_key) in keypairs.iter().zip(&private_keys) {
+            let single = Keypair::new(&curve, Some(private_key), None).unwrap();
+            assert_eq!(keypair.public_key, single.public_key);
+            let signature = keypair.sign(b"batch").unwrap();
+            assert!(single.verify(b"batch", &signature));
+        }
+        assert!(Keypair::new_batch(&curve, &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_sign_and_verify() {
+        let curve = tiny_ec::curve_registry::get_curve("brainpoolP160r1").unwrap();
+        let keypair = Keypair::new(&curve, Some(&BigInt::from(1337)), None).unwrap();
+
+        let signature = keypair.sign(b"sample").unwrap();
+        assert!(keypair.verify(b"sample", &signature));
+        assert!(!keypair.verify(b"samples", &signature));
+
+        let forged = Signature::new(signature.r.clone(), &signature.s + 1);
+        assert!(!keypair.verify(b"sample", &forged));
+    }
+
+    #[test]
+    fn test_signatures_are_deterministic() {
+        let curve = tiny_ec::curve_registry::get_curve("brainpoolP160r1").unwrap();
+        let keypair = Keypair::new(&curve, Some(&BigInt::from(1337)), None).unwrap();
+
+        assert_eq!(keypair.sign(b"sample"), keypair.sign(b"sample"));
+        assert_ne!(keypair.sign(b"sample"), keypair.sign(b"test"));
+    }
+
+    #[test]
+    fn test_verify_with_public_key_only() {
+        let curve = tiny_ec::curve_registry::get_curve("secp192r1").unwrap();
+        let signer = Keypair::new(&curve, Some(&BigInt::from(1337)), None).unwrap();
+        let verifier = Keypair::new(&curve, None, Some(&signer.public_key)).unwrap();
+
+        let signature = signer.sign(b"test").unwrap();
+        assert!(verifier.verify(b"test", &signature));
+        assert_eq!(verifier.sign(b"test"), Err(Error::MissingPrivateKey));
+    }
+
+    #[test]
+    fn test_verify_rfc6979_vector() {
+        // RFC 6979 A.2.3, P-192 with SHA-1 and message "sample"
+        let curve = tiny_ec::curve_registry::get_curve("secp192r1").unwrap();
+        let private_key =
+            BigInt::from_str_radix("6FAB034934E4C0FC9AE67F5B5659A9D7D1FEFD187EE09FD4", 16).unwrap();
+        let keypair = Keypair::new(&curve, Some(&private_key), None).unwrap();
+        let signature = Signature::new(
+            BigInt::from_str_radix("98C6BD12B23EAF5E2A2045132086BE3EB8EBD62ABF6698FF", 16).unwrap(),
+            BigInt::from_str_radix("57A22B07DEA9530F8DE9471B1DC6624472E8E2844BC25B64", 16).unwrap(),
+        );
+        assert!(keypair.verify(b"sample", &signature));
+    }
 }
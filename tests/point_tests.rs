@@ -2,22 +2,29 @@
 mod tests {
     use num_bigint::BigInt;
     use tiny_ec::curve::{Curve, SubGroup};
-    use tiny_ec::point::Point;
+    use tiny_ec::curve_registry::get_curve;
+    use tiny_ec::point::{Point, PIPPENGER_THRESHOLD, WNAF_WIDTHS};
+    use tiny_ec::scalar::Scalar;
+    use tiny_ec::Error;
 
-    #[test]
-    fn test_addition_on_curve() {
+    fn curve_97() -> Curve {
         let field = SubGroup {
             p: BigInt::from(97),
             g: (BigInt::from(1), BigInt::from(2)),
             n: BigInt::from(5),
             h: BigInt::from(1),
         };
-        let curve = Curve::new(
+        Curve::new(
             BigInt::from(2),
             BigInt::from(3),
             field,
             "testCurve".to_string(),
-        );
+        )
+    }
+
+    #[test]
+    fn test_addition_on_curve() {
+        let curve = curve_97();
 
         let p1 = Point::new(&curve, BigInt::from(22), BigInt::from(5)).unwrap();
         let p2 = Point::new(&curve, BigInt::from(95), BigInt::from(31)).unwrap();
@@ -32,38 +39,16 @@ mod tests {
 
     #[test]
     fn test_warning_on_invalid_point() {
-        let field = SubGroup {
-            p: BigInt::from(97),
-            g: (BigInt::from(1), BigInt::from(2)),
-            n: BigInt::from(5),
-            h: BigInt::from(1),
-        };
-        let curve = Curve::new(
-            BigInt::from(2),
-            BigInt::from(3),
-            field,
-            "testCurve".to_string(),
-        );
+        let curve = curve_97();
 
         let result = Point::new(&curve, BigInt::from(94), BigInt::from(31));
 
-        assert!(result.is_none());
+        assert_eq!(result, Err(Error::NotOnCurve));
     }
 
     #[test]
     fn test_addition_with_infinity() {
-        let field = SubGroup {
-            p: BigInt::from(97),
-            g: (BigInt::from(1), BigInt::from(2)),
-            n: BigInt::from(5),
-            h: BigInt::from(1),
-        };
-        let curve = Curve::new(
-            BigInt::from(2),
-            BigInt::from(3),
-            field,
-            "testCurve".to_string(),
-        );
+        let curve = curve_97();
 
         let p1 = Point::new(&curve, BigInt::from(22), BigInt::from(5)).unwrap();
         let inf = Point::inf(&curve);
@@ -74,18 +59,7 @@ mod tests {
 
     #[test]
     fn test_doubling_on_curve() {
-        let field = SubGroup {
-            p: BigInt::from(97),
-            g: (BigInt::from(1), BigInt::from(2)),
-            n: BigInt::from(5),
-            h: BigInt::from(1),
-        };
-        let curve = Curve::new(
-            BigInt::from(2),
-            BigInt::from(3),
-            field,
-            "testCurve".to_string(),
-        );
+        let curve = curve_97();
 
         let p1 = Point::new(&curve, BigInt::from(24), BigInt::from(2)).unwrap();
         let expected = Point::new(&curve, BigInt::from(65), BigInt::from(65)).unwrap();
@@ -95,18 +69,7 @@ mod tests {
 
     #[test]
     fn test_opposite_ordinates() {
-        let field = SubGroup {
-            p: BigInt::from(97),
-            g: (BigInt::from(1), BigInt::from(2)),
-            n: BigInt::from(5),
-            h: BigInt::from(1),
-        };
-        let curve = Curve::new(
-            BigInt::from(2),
-            BigInt::from(3),
-            field,
-            "testCurve".to_string(),
-        );
+        let curve = curve_97();
 
         let p1 = Point::new(&curve, BigInt::from(12), BigInt::from(3)).unwrap();
         let p2 = Point::new(&curve, BigInt::from(12), BigInt::from(94)).unwrap();
@@ -155,8 +118,500 @@ mod tests {
 
         let expected = Point::new(&curve, BigInt::from(1089), BigInt::from(6931)).unwrap();
         let x = Point::new(&curve, BigInt::from(5323), BigInt::from(5438)).unwrap();
+        assert_eq!(
+            expected,
+            Point::mul_montgomery(&x, BigInt::from(1337)).unwrap()
+        );
+        assert!(Point::mul_montgomery(&x, BigInt::from(0))
+            .unwrap()
+            .is_infinity());
+        assert_eq!(
+            expected.negate(),
+            Point::mul_montgomery(&x, BigInt::from(-1337)).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_wnaf_matches_double_and_add() {
+        for name in ["secp256k1", "P-256", "brainpoolP384r1"] {
+            let curve = get_curve(name).unwrap();
+            let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+            let n = &curve.field.n;
+            for k in [
+                BigInt::from(0),
+                BigInt::from(7),
+                n - 1,
+                n.clone(),
+                -(n >> 5u32),
+            ] {
+                let expected = Point::mul_double_and_add(&g, k.clone()).unwrap();
+                for w in WNAF_WIDTHS {
+                    assert_eq!(
+                        Point::mul_wnaf(&g, k.clone(), w).unwrap(),
+                        expected,
+                        "{}",
+                        name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_mul2() {
+        // secp256k1 takes the GLV path, P-256 the joint sparse form
+        for name in ["secp256k1", "P-256"] {
+            let curve = get_curve(name).unwrap();
+            let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+            let q = g.double() + &g;
+            let n = &curve.field.n;
+            let scalars = [
+                BigInt::from(0),
+                BigInt::from(1),
+                BigInt::from(53),
+                BigInt::from(102),
+                n - 1,
+                n >> 7u32,
+            ];
+            for a in &scalars {
+                for b in &scalars {
+                    let (sa, sb) = (
+                        Scalar::new(&curve, a.clone()),
+                        Scalar::new(&curve, b.clone()),
+                    );
+                    let expected = Point::add(
+                        &Point::mul_double_and_add(&g, a.clone()).unwrap(),
+                        &Point::mul_double_and_add(&q, b.clone()).unwrap(),
+                    )
+                    .unwrap();
+                    assert_eq!(Point::mul2(&g, &sa, &q, &sb).unwrap(), expected);
+                }
+            }
+            // P = Q exercises the doubling inside the addition of P + Q
+            let (sa, sb) = (
+                Scalar::new(&curve, BigInt::from(5)),
+                Scalar::new(&curve, BigInt::from(9)),
+            );
+            assert_eq!(
+                Point::mul2(&g, &sa, &g, &sb).unwrap(),
+                Point::mul_double_and_add(&g, BigInt::from(14)).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn test_multiscalar_mul() {
+        for name in ["secp256k1", "P-256"] {
+            let curve = get_curve(name).unwrap();
+            let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+            for len in [1, 5, PIPPENGER_THRESHOLD - 1, PIPPENGER_THRESHOLD, 70] {
+                let terms: Vec<(Scalar, Point)> = (0..len)
+                    .map(|i| {
+                        let point = Point::mul_scalar(&g, &Scalar::random(&curve)).unwrap();
+                        let k = if i % 7 == 0 {
+                            Scalar::zero(&curve)
+                        } else {
+                            Scalar::random(&curve)
+                        };
+                        (k, point)
+                    })
+                    .collect();
+                let expected = terms
+                    .iter()
+                    .map(|(k, point)| point * k)
+                    .fold(Point::inf(&curve), |acc, p| acc + p);
+                assert_eq!(Point::multiscalar_mul(&terms).unwrap(), expected, "{}", len);
+            }
+        }
+        assert_eq!(Point::multiscalar_mul(&[]), Err(Error::NoTerms));
+        let curve = get_curve("P-256").unwrap();
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+
+        let other = get_curve("secp256k1").unwrap();
+        let terms = [(Scalar::one(&other), g.clone())];
+        assert_eq!(Point::multiscalar_mul(&terms), Err(Error::CurveMismatch));
+    }
+
+    #[test]
+    fn test_glv_scalar_multiplication() {
+        for name in ["secp160k1", "secp192k1", "secp224k1", "secp256k1"] {
+            let curve = get_curve(name).unwrap();
+            assert!(curve.endomorphism().is_some());
+            let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+            let n = &curve.field.n;
+            let mut scalars = vec![BigInt::from(0), BigInt::from(1), n - 1, n >> 1u32];
+            scalars.extend((0..5).map(|_| Scalar::random(&curve).value().clone()));
+            for k in scalars {
+                let expected = Point::mul_double_and_add(&g, k.clone()).unwrap();
+                let k = Scalar::new(&curve, k);
+                assert_eq!(Point::mul_scalar(&g, &k).unwrap(), expected, "{}", name);
+            }
+        }
+    }
+
+    #[test]
+    fn test_wnaf_rejects_invalid_width() {
+        let curve = get_curve("P-256").unwrap();
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+        for w in [0, 1, 11] {
+            assert_eq!(
+                Point::mul_wnaf(&g, BigInt::from(5), w),
+                Err(Error::InvalidWindowWidth)
+            );
+        }
+    }
+
+    #[test]
+    fn test_montgomery_matches_double_and_add() {
+        for name in ["secp256k1", "P-256", "brainpoolP384r1"] {
+            let curve = get_curve(name).unwrap();
+            let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+            let n = &curve.field.n;
+            for k in [
+                BigInt::from(1),
+                BigInt::from(2),
+                n - 1,
+                n.clone(),
+                n + 1,
+                n >> 3,
+            ] {
+                assert_eq!(
+                    Point::mul_montgomery(&g, k.clone()).unwrap(),
+                    Point::mul_double_and_add(&g, k).unwrap(),
+                    "{}",
+                    name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_montgomery_operation_count_does_not_depend_on_k() {
+        use tiny_ec::jacobian::operation_count;
+
+        for name in ["P-256", "secp256k1", "brainpoolP160r1"] {
+            let curve = get_curve(name).unwrap();
+            let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+            let n = &curve.field.n;
+            let counts: Vec<u64> = [
+                BigInt::from(0),
+                BigInt::from(1),
+                BigInt::from(2),
+                n >> 100,
+                n - 1,
+            ]
+            .into_iter()
+            .map(|k| {
+                let before = operation_count();
+                Point::mul_montgomery(&g, k).unwrap();
+                operation_count() - before
+            })
+            .collect();
+            assert!(counts.iter().all(|count| *count == counts[0]), "{}", name);
+            assert_eq!(counts[0], 3 * n.bits() + 2, "{}", name);
+        }
+    }
+
+    // y^2 = x^3 + x over F_1019, where (0, 0) is a genuine point of order 2
+    fn curve_with_origin() -> Curve {
+        let field = SubGroup {
+            p: BigInt::from(1019),
+            g: (BigInt::from(4), BigInt::from(219)),
+            n: BigInt::from(5),
+            h: BigInt::from(1),
+        };
+        Curve::new(
+            BigInt::from(1),
+            BigInt::from(0),
+            field,
+            "origin".to_string(),
+        )
+    }
+
+    #[test]
+    fn test_origin_is_not_infinity() {
+        let curve = curve_with_origin();
+        let origin = Point::new(&curve, BigInt::from(0), BigInt::from(0)).unwrap();
+        let inf = Point::inf(&curve);
+
+        assert!(!origin.is_infinity());
+        assert!(inf.is_infinity());
+        assert_ne!(origin, inf);
+        assert_eq!(origin.to_string(), "0,0");
+        assert_eq!(inf.to_string(), "inf");
+        assert_eq!(origin.compress(), Ok((BigInt::from(0), 0)));
+        assert_eq!(inf.compress(), Err(Error::PointAtInfinity));
+    }
+
+    #[test]
+    fn test_addition_with_origin() {
+        let curve = curve_with_origin();
+        let origin = Point::new(&curve, BigInt::from(0), BigInt::from(0)).unwrap();
+        let p1 = Point::new(&curve, BigInt::from(4), BigInt::from(219)).unwrap();
+        let expected = Point::new(&curve, BigInt::from(255), BigInt::from(50)).unwrap();
+
+        assert_eq!(expected, Point::add(&p1, &origin).unwrap());
+        assert_eq!(expected, Point::add(&origin, &p1).unwrap());
+        assert!(Point::add(&origin, &origin).unwrap().is_infinity());
+        assert_eq!(origin, Point::add(&origin, &Point::inf(&curve)).unwrap());
+        assert_eq!(
+            origin,
+            Point::mul_double_and_add(&origin, BigInt::from(3)).unwrap()
+        );
+        assert!(Point::mul_double_and_add(&origin, BigInt::from(2))
+            .unwrap()
+            .is_infinity());
+    }
+
+    #[test]
+    fn test_operators() {
+        let curve = curve_97();
+        let p1 = Point::new(&curve, BigInt::from(22), BigInt::from(5)).unwrap();
+        let p2 = Point::new(&curve, BigInt::from(95), BigInt::from(31)).unwrap();
+        let sum = Point::new(&curve, BigInt::from(29), BigInt::from(43)).unwrap();
+
+        assert_eq!(&p1 + &p2, sum);
+        assert_eq!(p1.clone() + p2.clone(), sum);
+        assert_eq!(&sum - &p2, p1);
+        assert_eq!(sum.clone() - p1.clone(), p2);
+        assert_eq!(
+            -&p1,
+            Point::new(&curve, BigInt::from(22), BigInt::from(92)).unwrap()
+        );
+        assert!((&p1 - &p1).is_infinity());
+        assert!((-Point::inf(&curve)).is_infinity());
+
+        let mut acc = p1.clone();
+        acc += &p2;
+        assert_eq!(acc, sum);
+        acc += Point::inf(&curve);
+        assert_eq!(acc, sum);
+    }
+
+    #[test]
+    fn test_double_and_negate() {
+        let curve = curve_97();
+        let p1 = Point::new(&curve, BigInt::from(24), BigInt::from(2)).unwrap();
+        let expected = Point::new(&curve, BigInt::from(65), BigInt::from(65)).unwrap();
+
+        assert_eq!(p1.double(), expected);
+        assert_eq!(p1.negate().negate(), p1);
+        assert_eq!(p1.negate().double(), expected.negate());
+        assert!(Point::inf(&curve).double().is_infinity());
+    }
+
+    #[test]
+    fn test_scalar_multiplication_operator() {
+        let field = SubGroup {
+            p: BigInt::from(9739),
+            g: (BigInt::from(1), BigInt::from(2)),
+            n: BigInt::from(5),
+            h: BigInt::from(1),
+        };
+        let curve = Curve::new(
+            BigInt::from(497),
+            BigInt::from(1768),
+            field,
+            "testCurve".to_string(),
+        );
+
+        let x = Point::new(&curve, BigInt::from(5323), BigInt::from(5438)).unwrap();
+        let expected = Point::new(&curve, BigInt::from(1089), BigInt::from(6931)).unwrap();
         let k = BigInt::from(1337);
-        let n = k.bits() as usize;
-        assert_eq!(expected, Point::mul_montgomery(&x, k, n).unwrap())
+        assert_eq!(&x * &k, expected);
+        assert_eq!(&k * &x, expected);
+        assert_eq!(x.clone() * k.clone(), expected);
+        assert_eq!(&x * BigInt::from(2), x.double());
+        assert_eq!(&x * &k + &x, &x * BigInt::from(1338));
+    }
+
+    #[test]
+    fn test_sum() {
+        let curve = curve_97();
+        let p1 = Point::new(&curve, BigInt::from(22), BigInt::from(5)).unwrap();
+        let p2 = Point::new(&curve, BigInt::from(95), BigInt::from(31)).unwrap();
+        let points = vec![p1.clone(), p2.clone(), Point::inf(&curve), p1.clone()];
+
+        let expected = &(&p1 + &p2) + &p1;
+        assert_eq!(Point::sum(&curve, &points).unwrap(), expected);
+        assert!(Point::sum(&curve, &[p1.clone(), -&p1])
+            .unwrap()
+            .is_infinity());
+        assert!(Point::sum(&curve, &[]).unwrap().is_infinity());
+
+        let other = curve_with_origin();
+        let origin = Point::new(&other, BigInt::from(0), BigInt::from(0)).unwrap();
+        assert_eq!(Point::sum(&curve, &[p1, origin]), Err(Error::CurveMismatch));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_operators_panic_on_curve_mismatch() {
+        let curve = curve_97();
+        let other = curve_with_origin();
+        let p1 = Point::new(&curve, BigInt::from(22), BigInt::from(5)).unwrap();
+        let origin = Point::new(&other, BigInt::from(0), BigInt::from(0)).unwrap();
+        let _ = p1 + origin;
+    }
+
+    #[test]
+    fn test_coordinates_are_reduced() {
+        let curve = curve_97();
+        let p1 = Point::new(&curve, BigInt::from(22), BigInt::from(5)).unwrap();
+        let unreduced = Point::new(&curve, BigInt::from(22 + 97), BigInt::from(5 - 97)).unwrap();
+
+        assert_eq!(unreduced, p1);
+        assert_eq!(unreduced.x, BigInt::from(22));
+        assert_eq!(unreduced.y, BigInt::from(5));
+    }
+
+    #[test]
+    fn test_sec1_roundtrip_on_registry_curves() {
+        for name in tiny_ec::curve_registry::list_curves() {
+            let curve = get_curve(&name).unwrap();
+            let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+            let len = curve.field.p.bits().div_ceil(8) as usize;
+            for point in [g.clone(), g.double(), -&g] {
+                let compressed = point.to_sec1_bytes(true);
+                assert_eq!(compressed.len(), 1 + len);
+                assert_eq!(Point::from_sec1_bytes(&curve, &compressed).unwrap(), point);
+
+                let uncompressed = point.to_sec1_bytes(false);
+                assert_eq!(uncompressed.len(), 1 + 2 * len);
+                assert_eq!(
+                    Point::from_sec1_bytes(&curve, &uncompressed).unwrap(),
+                    point
+                );
+
+                let mut hybrid = uncompressed.clone();
+                hybrid[0] = 0x06 | u8::from(point.y.bit(0));
+                assert_eq!(
+                    Point::from_sec1_bytes(&curve, &hybrid).unwrap(),
+                    point,
+                    "{}",
+                    name
+                );
+            }
+            let inf = Point::inf(&curve);
+            assert_eq!(inf.to_sec1_bytes(true), vec![0x00]);
+            assert_eq!(Point::from_sec1_bytes(&curve, &[0x00]).unwrap(), inf);
+        }
+    }
+
+    #[test]
+    fn test_sec1_p256_generator() {
+        let curve = get_curve("P-256").unwrap();
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+        let x = "6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296";
+        let y = "4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5";
+        assert_eq!(hex::encode(g.to_sec1_bytes(true)), format!("03{}", x));
+        assert_eq!(hex::encode(g.to_sec1_bytes(false)), format!("04{}{}", x, y));
+    }
+
+    #[test]
+    fn test_sec1_rejects_malformed_input() {
+        let curve = get_curve("P-256").unwrap();
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+        let compressed = g.to_sec1_bytes(true);
+        let uncompressed = g.to_sec1_bytes(false);
+        let invalid = |bytes: &[u8]| Point::from_sec1_bytes(&curve, bytes).unwrap_err();
+
+        assert_eq!(invalid(&[]), Error::InvalidEncoding);
+        assert_eq!(invalid(&[0x00, 0x00]), Error::InvalidEncoding);
+        assert_eq!(invalid(&compressed[..32]), Error::InvalidEncoding);
+        assert_eq!(invalid(&uncompressed[..64]), Error::InvalidEncoding);
+
+        let mut bad_tag = compressed.clone();
+        bad_tag[0] = 0x05;
+        assert_eq!(invalid(&bad_tag), Error::InvalidEncoding);
+
+        // Hybrid encodings must carry the parity of y
+        let mut hybrid = uncompressed.clone();
+        hybrid[0] = 0x06;
+        assert_eq!(invalid(&hybrid), Error::InvalidEncoding);
+
+        // x = p is the same residue as x = 0 but not a canonical encoding
+        let mut x_is_p = vec![0x02];
+        x_is_p.extend(curve.field.p.to_bytes_be().1);
+        assert_eq!(invalid(&x_is_p), Error::InvalidEncoding);
+
+        let mut off_curve = uncompressed.clone();
+        off_curve[64] ^= 1;
+        assert_eq!(invalid(&off_curve), Error::NotOnCurve);
+
+        // x^3 - 3x + b is not a square for x = 1 on P-256
+        let mut no_root = vec![0x02; 1];
+        no_root.extend([0; 31]);
+        no_root.push(1);
+        assert_eq!(invalid(&no_root), Error::NotAResidue);
+    }
+
+    #[test]
+    fn test_decompress_inverts_compress_on_registry_curves() {
+        for name in tiny_ec::curve_registry::list_curves() {
+            let curve = get_curve(&name).unwrap();
+            let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+            let mut parities = [false; 2];
+            for _ in 0..32 {
+                let point = &g * Scalar::random(&curve);
+                let (x, is_odd) = point.compress().unwrap();
+                parities[usize::from(is_odd)] = true;
+                let decompressed = Point::decompress(&curve, x, is_odd).unwrap();
+                assert_eq!(decompressed, point, "{}", name);
+            }
+            assert_eq!(parities, [true, true], "{}", name);
+
+            let p = &curve.field.p;
+            let (x, is_odd) = g.compress().unwrap();
+            for unreduced in [p.clone(), x + p] {
+                assert_eq!(
+                    Point::decompress(&curve, unreduced, is_odd),
+                    Err(Error::InvalidEncoding),
+                    "{}",
+                    name
+                );
+            }
+
+            // About half of all x have no point; take the first
+            let fp = curve.base_field();
+            let no_root = (0u32..)
+                .map(BigInt::from)
+                .find(|x| curve.y_squared(&fp.element(x)).legendre() == -1)
+                .unwrap();
+            assert_eq!(
+                Point::decompress(&curve, no_root, 0),
+                Err(Error::NotAResidue),
+                "{}",
+                name
+            );
+        }
+    }
+
+    #[test]
+    fn test_decompress_rejects_invalid_input() {
+        let curve = get_curve("P-256").unwrap();
+        let p = curve.field.p.clone();
+        let (x, _) = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone())
+            .unwrap()
+            .compress()
+            .unwrap();
+
+        assert_eq!(
+            Point::decompress(&curve, x.clone(), 2).unwrap_err(),
+            Error::InvalidEncoding
+        );
+        assert_eq!(
+            Point::decompress(&curve, &x + &p, 0).unwrap_err(),
+            Error::InvalidEncoding
+        );
+        assert_eq!(
+            Point::decompress(&curve, -x, 0).unwrap_err(),
+            Error::InvalidEncoding
+        );
+        // x^3 - 3x + b is not a square for x = 1 on P-256
+        assert_eq!(
+            Point::decompress(&curve, BigInt::from(1), 0).unwrap_err(),
+            Error::NotAResidue
+        );
     }
 }
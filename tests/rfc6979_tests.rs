@@ -0,0 +1,165 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use num_traits::Num;
+    use sha1::Sha1;
+    use sha2::{Digest, Sha224, Sha256, Sha384, Sha512};
+    use tiny_ec::key_pair::Keypair;
+    use tiny_ec::rfc6979::{generate_k, NonceGenerator};
+
+    fn hex(s: &str) -> BigInt {
+        BigInt::from_str_radix(s, 16).unwrap()
+    }
+
+    #[test]
+    fn test_detailed_example() {
+        // RFC 6979 A.1, 163-bit order with SHA-256 and message "sample"
+        let q = hex("4000000000000000000020108A2E0CC0D99F8A5EF");
+        let x = hex("09A4D6792295A7F730FC3F2B49CBC0F62E862272F");
+        let h1 = Sha256::digest(b"sample");
+
+        let k = generate_k::<Sha256>(&q, &x, &h1);
+        assert_eq!(k, hex("23AF4074C90A02B3FE61D286D5C87F425E6BDD81B"));
+    }
+
+    #[test]
+    fn test_nonces_are_distinct_and_in_range() {
+        let q = hex("4000000000000000000020108A2E0CC0D99F8A5EF");
+        let x = hex("09A4D6792295A7F730FC3F2B49CBC0F62E862272F");
+        let h1 = Sha256::digest(b"sample");
+
+        let nonces: Vec<BigInt> = NonceGenerator::<Sha256>::new(&q, &x, &h1).take(3).collect();
+        assert_eq!(nonces[0], generate_k::<Sha256>(&q, &x, &h1));
+        assert_ne!(nonces[0], nonces[1]);
+        assert_ne!(nonces[1], nonces[2]);
+        assert!(nonces.iter().all(|k| k > &BigInt::from(0) && k < &q));
+    }
+
+    #[test]
+    fn test_p192_vectors() {
+        // RFC 6979 A.2.3
+        let curve = tiny_ec::curve_registry::get_curve("secp192r1").unwrap();
+        let x = hex("6FAB034934E4C0FC9AE67F5B5659A9D7D1FEFD187EE09FD4");
+        let keypair = Keypair::new(&curve, Some(&x), None).unwrap();
+        let n = &curve.field.n;
+
+        let h1 = Sha1::digest(b"sample");
+        assert_eq!(
+            generate_k::<Sha1>(n, &x, &h1),
+            hex("37D7CA00D2C7B0E5E412AC03BD44BA837FDD5B28CD3B0021")
+        );
+        let signature = keypair.sign_with::<Sha1>(b"sample").unwrap();
+        assert_eq!(
+            signature.r,
+            hex("98C6BD12B23EAF5E2A2045132086BE3EB8EBD62ABF6698FF")
+        );
+        assert_eq!(
+            signature.s,
+            hex("57A22B07DEA9530F8DE9471B1DC6624472E8E2844BC25B64")
+        );
+
+        let signature = keypair.sign_with::<Sha256>(b"sample").unwrap();
+        assert_eq!(
+            signature.r,
+            hex("4B0B8CE98A92866A2820E20AA6B75B56382E0F9BFD5ECB55")
+        );
+        assert_eq!(
+            signature.s,
+            hex("CCDB006926EA9565CBADC840829D8C384E06DE1F1E381B85")
+        );
+        assert!(keypair.verify_with::<Sha256>(b"sample", &signature));
+
+        let signature = keypair.sign_with::<Sha256>(b"test").unwrap();
+        assert_eq!(
+            signature.r,
+            hex("3A718BD8B4926C3B52EE6BBE67EF79B18CB6EB62B1AD97AE")
+        );
+        assert_eq!(
+            signature.s,
+            hex("5662E6848A4A19B1F1AE2F72ACD4B8BBE50F1EAC65D9124F")
+        );
+    }
+
+    #[test]
+    fn test_p192_nonces_for_all_hashes() {
+        // RFC 6979 A.2.3, message "sample"
+        let curve = tiny_ec::curve_registry::get_curve("secp192r1").unwrap();
+        let x = hex("6FAB034934E4C0FC9AE67F5B5659A9D7D1FEFD187EE09FD4");
+        let n = &curve.field.n;
+
+        assert_eq!(
+            generate_k::<Sha224>(n, &x, &Sha224::digest(b"sample")),
+            hex("4381526B3FC1E7128F202E194505592F01D5FF4C5AF015D8")
+        );
+        assert_eq!(
+            generate_k::<Sha256>(n, &x, &Sha256::digest(b"sample")),
+            hex("32B1B6D7D42A05CB449065727A84804FB1A3E34D8F261496")
+        );
+        assert_eq!(
+            generate_k::<Sha384>(n, &x, &Sha384::digest(b"sample")),
+            hex("4730005C4FCB01834C063A7B6760096DBE284B8252EF4311")
+        );
+        assert_eq!(
+            generate_k::<Sha512>(n, &x, &Sha512::digest(b"sample")),
+            hex("A2AC7AB055E4F20692D49209544C203A7D1F2C0BFBC75DB1")
+        );
+    }
+
+    // RFC 6979 A.2.4 to A.2.7, message "sample" with SHA-256
+    fn check_sha256_sample(name: &str, x: &str, k: &str, r: &str, s: &str) {
+        let curve = tiny_ec::curve_registry::get_curve(name).unwrap();
+        let x = hex(x);
+        let keypair = Keypair::new(&curve, Some(&x), None).unwrap();
+
+        let h1 = Sha256::digest(b"sample");
+        assert_eq!(generate_k::<Sha256>(&curve.field.n, &x, &h1), hex(k));
+        let signature = keypair.sign_with::<Sha256>(b"sample").unwrap();
+        assert_eq!(signature.r, hex(r));
+        assert_eq!(signature.s, hex(s));
+        assert!(keypair.verify_with::<Sha256>(b"sample", &signature));
+    }
+
+    #[test]
+    fn test_p224_sha256_vector() {
+        check_sha256_sample(
+            "secp224r1",
+            "F220266E1105BFE3083E03EC7A3A654651F45E37167E88600BF257C1",
+            "AD3029E0278F80643DE33917CE6908C70A8FF50A411F06E41DEDFCDC",
+            "61AA3DA010E8E8406C656BC477A7A7189895E7E840CDFE8FF42307BA",
+            "BC814050DAB5D23770879494F9E0A680DC1AF7161991BDE692B10101",
+        );
+    }
+
+    #[test]
+    fn test_p256_sha256_vector() {
+        check_sha256_sample(
+            "secp256r1",
+            "C9AFA9D845BA75166B5C215767B1D6934E50C3DB36E89B127B8A622B120F6721",
+            "A6E3C57DD01ABE90086538398355DD4C3B17AA873382B0F24D6129493D8AAD60",
+            "EFD48B2AACB6A8FD1140DD9CD45E81D69D2C877B56AAF991C34D0EA84EAF3716",
+            "F7CB1C942D657C41D436C7A1B6E29F65F3E900DBB9AFF4064DC4AB2F843ACDA8",
+        );
+    }
+
+    #[test]
+    fn test_p384_sha256_vector() {
+        check_sha256_sample(
+            "secp384r1",
+            "6B9D3DAD2E1B8C1C05B19875B6659F4DE23C3B667BF297BA9AA47740787137D896D5724E4C70A825F872C9EA60D2EDF5",
+            "180AE9F9AEC5438A44BC159A1FCB277C7BE54FA20E7CF404B490650A8ACC414E375572342863C899F9F2EDF9747A9B60",
+            "21B13D1E013C7FA1392D03C5F99AF8B30C570C6F98D4EA8E354B63A21D3DAA33BDE1E888E63355D92FA2B3C36D8FB2CD",
+            "F3AA443FB107745BF4BD77CB3891674632068A10CA67E3D45DB2266FA7D1FEEBEFDC63ECCD1AC42EC0CB8668A4FA0AB0",
+        );
+    }
+
+    #[test]
+    fn test_p521_sha256_vector() {
+        check_sha256_sample(
+            "secp521r1",
+            "0FAD06DAA62BA3B25D2FB40133DA757205DE67F5BB0018FEE8C86E1B68C7E75CAA896EB32F1F47C70855836A6D16FCC1466F6D8FBEC67DB89EC0C08B0E996B83538",
+            "0EDF38AFCAAECAB4383358B34D67C9F2216C8382AAEA44A3DAD5FDC9C32575761793FEF24EB0FC276DFC4F6E3EC476752F043CF01415387470BCBD8678ED2C7E1A0",
+            "1511BB4D675114FE266FC4372B87682BAECC01D3CC62CF2303C92B3526012659D16876E25C7C1E57648F23B73564D67F61C6F14D527D54972810421E7D87589E1A7",
+            "04A171143A83163D6DF460AAF61522695F207A58B95C0644D87E52AA1A347916E4F7A72930B1BC06DBE22CE3F58264AFD23704CBB63B29B931F7DE6C9D949A7ECFC",
+        );
+    }
+}
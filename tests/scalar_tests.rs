@@ -0,0 +1,111 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use num_traits::One;
+    use sha2::{Digest, Sha256};
+    use tiny_ec::curve_registry::get_curve;
+    use tiny_ec::point::Point;
+    use tiny_ec::scalar::Scalar;
+    use tiny_ec::Error;
+
+    #[test]
+    fn test_reduction_modulo_n() {
+        let curve = get_curve("brainpoolP160r1").unwrap();
+        let n = &curve.field.n;
+
+        assert!(Scalar::new(&curve, n.clone()).is_zero());
+        assert_eq!(Scalar::new(&curve, n + 5).value(), &BigInt::from(5));
+        assert_eq!(Scalar::new(&curve, BigInt::from(-1)).value(), &(n - 1));
+        assert_eq!(
+            Scalar::new(&curve, BigInt::from(-3)),
+            -Scalar::new(&curve, BigInt::from(3))
+        );
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let curve = get_curve("brainpoolP160r1").unwrap();
+        let n = &curve.field.n;
+        let a = Scalar::new(&curve, n - 2);
+        let b = Scalar::new(&curve, BigInt::from(7));
+
+        assert_eq!((&a + &b).value(), &BigInt::from(5));
+        assert_eq!((&b - &a).value(), &BigInt::from(9));
+        assert_eq!((&a * &b).value(), &(n - 14));
+        assert!((&a + &(-&a)).is_zero());
+
+        let b_inv = b.invert().unwrap();
+        assert_eq!(&b * &b_inv, Scalar::one(&curve));
+        assert_eq!(Scalar::zero(&curve).invert(), Err(Error::NonInvertible));
+    }
+
+    #[test]
+    fn test_from_bytes_and_digest() {
+        let curve = get_curve("secp256k1").unwrap();
+        let n = &curve.field.n;
+
+        let bytes = vec![0xff; 32];
+        let expected = (BigInt::one() << 256) - 1 - n;
+        assert_eq!(Scalar::from_bytes_be(&curve, &bytes).value(), &expected);
+        assert_eq!(
+            Scalar::from_bytes_be(&curve, &[0x01, 0x00]).value(),
+            &BigInt::from(256)
+        );
+
+        let digest = Sha256::digest(b"sample");
+        let e = Scalar::from_digest(&curve, &digest);
+        assert_eq!(e, Scalar::hash::<Sha256>(&curve, b"sample"));
+        assert_eq!(e, Scalar::from_bytes_be(&curve, &digest));
+        assert_eq!(e.to_bytes_be().len(), 32);
+
+        // brainpoolP160r1 keeps only the leftmost 160 bits of a SHA-256 digest
+        let curve = get_curve("brainpoolP160r1").unwrap();
+        let e = Scalar::from_digest(&curve, &digest);
+        assert_eq!(e, Scalar::from_bytes_be(&curve, &digest[..20]));
+        assert_eq!(e.to_bytes_be().len(), 20);
+    }
+
+    #[test]
+    fn test_random() {
+        let curve = get_curve("brainpoolP160r1").unwrap();
+        for _ in 0..20 {
+            let k = Scalar::random(&curve);
+            assert!(!k.is_zero());
+            assert!(k.value() < &curve.field.n);
+        }
+    }
+
+    #[test]
+    fn test_scalar_multiplication() {
+        let curve = get_curve("brainpoolP160r1").unwrap();
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+        let k = BigInt::from(1337);
+        let expected = Point::mul_double_and_add(&g, k.clone()).unwrap();
+
+        let scalar = Scalar::new(&curve, k.clone());
+        assert_eq!(Point::mul_scalar(&g, &scalar).unwrap(), expected);
+        assert_eq!(&g * &scalar, expected);
+        assert_eq!(&scalar * &g, expected);
+
+        // Scalars are reduced modulo n, so k + n gives the same point
+        let wrapped = Scalar::new(&curve, &k + &curve.field.n);
+        assert_eq!(&g * wrapped, expected);
+
+        // Negative scalars multiply the negated point
+        assert_eq!(
+            Point::mul_double_and_add(&g, -&k).unwrap(),
+            expected.negate()
+        );
+        assert_eq!(&g * &(-&k), -&expected);
+        assert_eq!(&g * -scalar, -&expected);
+    }
+
+    #[test]
+    fn test_curve_mismatch() {
+        let curve = get_curve("brainpoolP160r1").unwrap();
+        let other = get_curve("secp192r1").unwrap();
+        let g = Point::new(&curve, curve.field.g.0.clone(), curve.field.g.1.clone()).unwrap();
+        let k = Scalar::one(&other);
+        assert_eq!(Point::mul_scalar(&g, &k), Err(Error::CurveMismatch));
+    }
+}
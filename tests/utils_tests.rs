@@ -0,0 +1,172 @@
+#[cfg(test)]
+mod tests {
+    use num_bigint::BigInt;
+    use num_traits::Num;
+    use tiny_ec::curve::{Curve, SubGroup};
+    use tiny_ec::field_element::PrimeField;
+    use tiny_ec::point::Point;
+    use tiny_ec::utils::{batch_invert, is_probable_prime, jsf, legendre, modsqrt, wnaf};
+    use tiny_ec::Error;
+
+    fn check_all_residues(p: u32) {
+        let squares: Vec<u32> = (0..p).map(|x| x * x % p).collect();
+        let modulus = BigInt::from(p);
+        for a in 0..p {
+            match modsqrt(&BigInt::from(a), &modulus) {
+                Ok(root) => assert_eq!(root.modpow(&BigInt::from(2), &modulus), a.into()),
+                Err(_) => assert!(!squares.contains(&a)),
+            }
+        }
+    }
+
+    #[test]
+    fn test_modsqrt_3_mod_4() {
+        check_all_residues(23);
+        check_all_residues(9739);
+    }
+
+    #[test]
+    fn test_modsqrt_5_mod_8() {
+        check_all_residues(13);
+        check_all_residues(101);
+    }
+
+    #[test]
+    fn test_modsqrt_1_mod_8() {
+        check_all_residues(17);
+        check_all_residues(97);
+        check_all_residues(257);
+    }
+
+    #[test]
+    fn test_modsqrt_non_residue() {
+        assert_eq!(
+            modsqrt(&BigInt::from(5), &BigInt::from(23)),
+            Err(Error::NotAResidue)
+        );
+        assert_eq!(
+            modsqrt(&BigInt::from(3), &BigInt::from(17)),
+            Err(Error::NotAResidue)
+        );
+        assert_eq!(legendre(&BigInt::from(5), &BigInt::from(23)), -1);
+        assert_eq!(legendre(&BigInt::from(4), &BigInt::from(23)), 1);
+        assert_eq!(legendre(&BigInt::from(46), &BigInt::from(23)), 0);
+    }
+
+    #[test]
+    fn test_is_probable_prime() {
+        let primes = [2, 3, 47, 53, 997, 1009, 7919];
+        let composites = [0, 1, 4, 561, 1105, 8911, 1009 * 997];
+        assert!(primes.iter().all(|p| is_probable_prime(&BigInt::from(*p))));
+        assert!(!composites
+            .iter()
+            .any(|c| is_probable_prime(&BigInt::from(*c))));
+
+        let m127 = (BigInt::from(1) << 127) - 1;
+        assert!(is_probable_prime(&m127));
+        assert!(!is_probable_prime(&(&m127 * &m127)));
+    }
+
+    #[test]
+    fn test_modsqrt_p224() {
+        // P-224 prime is 1 mod 2^96, the worst case for Tonelli-Shanks
+        let p = BigInt::from_str_radix(
+            "ffffffffffffffffffffffffffffffff000000000000000000000001",
+            16,
+        )
+        .unwrap();
+        let x = BigInt::from_str_radix(
+            "b70e0cbd6bb4bf7f321390b94a03c1d356c21122343280d6115c1d21",
+            16,
+        )
+        .unwrap();
+        let square = x.modpow(&BigInt::from(2), &p);
+        let root = modsqrt(&square, &p).unwrap();
+        assert!(root == x || root == &p - &x);
+    }
+
+    #[test]
+    fn test_decompress_1_mod_4() {
+        let field = SubGroup {
+            p: BigInt::from(97),
+            g: (BigInt::from(24), BigInt::from(2)),
+            n: BigInt::from(25),
+            h: BigInt::from(4),
+        };
+        let curve = Curve::new(
+            BigInt::from(2),
+            BigInt::from(3),
+            field,
+            "testCurve".to_string(),
+        );
+
+        let p1 = Point::new(&curve, BigInt::from(22), BigInt::from(5)).unwrap();
+        let (x, is_odd) = p1.compress().unwrap();
+        let decompressed = Point::decompress(&curve, x, is_odd).unwrap();
+        assert_eq!(decompressed, p1);
+    }
+
+    #[test]
+    fn test_wnaf() {
+        let k = BigInt::from_str_radix("c0ffee5eed15dec0de0f1ee7babe", 16).unwrap();
+        for w in 2..=8 {
+            let digits = wnaf(&k, w);
+            let value = digits
+                .iter()
+                .rev()
+                .fold(BigInt::from(0), |acc, d| acc * 2 + d);
+            assert_eq!(value, k);
+            for (i, d) in digits.iter().enumerate() {
+                if *d != 0 {
+                    assert!(d % 2 != 0 && d.abs() < 1 << (w - 1));
+                    let next = &digits[i + 1..digits.len().min(i + w as usize)];
+                    assert!(next.iter().all(|d| *d == 0));
+                }
+            }
+            assert_ne!(digits.last(), Some(&0));
+        }
+        assert!(wnaf(&BigInt::from(0), 4).is_empty());
+        assert_eq!(wnaf(&BigInt::from(7), 3), vec![-1, 0, 0, 1]);
+    }
+
+    #[test]
+    fn test_jsf() {
+        let value = |digits: &[i8]| {
+            digits
+                .iter()
+                .rev()
+                .fold(BigInt::from(0), |acc, d| acc * 2 + d)
+        };
+        let pairs = [
+            (53, 102),
+            (0, 7),
+            (255, 256),
+            (0xdead_beef_u64, 0xc0ff_ee00_u64),
+        ];
+        for (k0, k1) in pairs {
+            let (k0, k1) = (BigInt::from(k0), BigInt::from(k1));
+            let digits = jsf(&k0, &k1);
+            let (d0, d1): (Vec<i8>, Vec<i8>) = digits.iter().copied().unzip();
+            assert_eq!((value(&d0), value(&d1)), (k0.clone(), k1.clone()));
+            // Of any three consecutive pairs at least one is (0, 0)
+            for window in digits.windows(3) {
+                assert!(window.contains(&(0, 0)), "{} {}", k0, k1);
+            }
+        }
+        assert!(jsf(&BigInt::from(0), &BigInt::from(0)).is_empty());
+    }
+
+    #[test]
+    fn test_batch_invert() {
+        let fp = PrimeField::new(BigInt::from(1009)).unwrap();
+        let values: Vec<_> = (1..50).map(|i| fp.element(&BigInt::from(i * 37))).collect();
+        let inverses = batch_invert(&values).unwrap();
+        for (value, inverse) in values.iter().zip(&inverses) {
+            assert_eq!(inverse, &value.invert().unwrap());
+        }
+        assert!(batch_invert(&[]).unwrap().is_empty());
+
+        let with_zero = [fp.one(), fp.zero()];
+        assert_eq!(batch_invert(&with_zero), Err(Error::NonInvertible));
+    }
+}